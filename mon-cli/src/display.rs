@@ -138,6 +138,10 @@ pub fn display_error(err: BattleError)
 		{
 			"Invalid target."
 		}
+		BattleError::AttackLearn =>
+		{
+			"Selected move cannot be learned."
+		}
 		BattleError::SwitchActive =>
 		{
 			"Selected party member is already active."
@@ -150,6 +154,38 @@ pub fn display_error(err: BattleError)
 		{
 			"Selected party member is already queued to switch in."
 		}
+		BattleError::SwitchTrapped =>
+		{
+			"Active party member is trapped and cannot switch out."
+		}
+		BattleError::ItemUnusable =>
+		{
+			"Selected item cannot be used in battle."
+		}
+		BattleError::ItemTarget =>
+		{
+			"Selected party member has no health."
+		}
+		BattleError::ItemEmpty =>
+		{
+			"There are none of the selected item left."
+		}
+		BattleError::RecruitTarget =>
+		{
+			"Selected party member cannot be recruited."
+		}
+		BattleError::RecruitOwned =>
+		{
+			"Selected party member already belongs to someone."
+		}
+		BattleError::RecruitKind =>
+		{
+			"Selected party member belongs to a trainer."
+		}
+		BattleError::EscapeKind =>
+		{
+			"There's no running from a trainer battle!"
+		}
 	};
 	println!("Invalid selection: {}", error_str);
 }
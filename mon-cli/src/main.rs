@@ -12,8 +12,8 @@ use mon_gen::monster::Monster;
 use mon_gen::species::{SpeciesType, FormId};
 use mon_gen::species::form::DeoxysForm;
 use mon_gen::battle;
-use mon_gen::battle::{Party, Effect, NoneReason, StatModifiers, StatModifierType};
-use mon_gen::battle::{Battle, BattleError, BattleExecution, LingeringType};
+use mon_gen::battle::{Party, PartyKind, Effect, NoneReason, StatModifiers, StatModifierType};
+use mon_gen::battle::{Battle, BattleError, BattleExecution, BattleOutcome, LingeringType};
 use rand::distributions::{IndependentSample, Range};
 
 use display::{display, display_member, display_active, display_error, display_party, display_attacks};
@@ -52,7 +52,7 @@ fn battle_prompt_switch(battle: &Battle, active: usize, party: usize, back: bool
 	}
 }
 
-fn battle_prompt_target(battle: &Battle) -> Option<(usize, usize)>
+fn battle_prompt_target(battle: &Battle, active: usize, attack: usize) -> Option<(usize, usize)>
 {
 		let mut target_map = HashMap::new();
 		for party_index in (0..battle.state().parties().len()).rev()
@@ -64,7 +64,9 @@ fn battle_prompt_target(battle: &Battle) -> Option<(usize, usize)>
 				let opponent = party_index & 1 == 1;
 				display(format!("{})", target_index + 1), opponent);
 				display_member(battle.state().parties()[party_index].active_member_alive(index),
-					opponent, false)
+					opponent, false);
+				let hit_chance = battle.hit_chance(0, active, attack, party_index, index);
+				display(format!("   Hit chance: {:.0}%", hit_chance * 100.0), opponent);
 			}
 		}
 
@@ -282,9 +284,15 @@ fn battle_execute_effect(battle: &Battle)
 			let member = battle.state().parties()[gain.party].member(gain.member);
 			println!("{} gained {} exp.", member.nick(), gain.amount);
 			terminal::wait();
-			if gain.level != member.level()
+		}
+		Effect::LevelUp(ref level_up) =>
+		{
+			let member = battle.state().parties()[level_up.party].member(level_up.member);
+			println!("{} grew to level {}!", member.nick(), level_up.level_up.level);
+			terminal::wait();
+			for &(_, attack) in &level_up.level_up.attacks
 			{
-				println!("{} leveled up!", member.nick());
+				println!("{} can learn {}!", member.nick(), attack.attack().name());
 				terminal::wait();
 			}
 		}
@@ -315,6 +323,102 @@ fn battle_execute_effect(battle: &Battle)
 			}
 			terminal::wait();
 		}
+		Effect::AilmentInflict(ref inflict) =>
+		{
+			let member = &battle.state().parties()[inflict.party].active_member(inflict.active);
+			println!("{} is afflicted with {:?}!", member.member.nick(), inflict.ailment);
+			terminal::wait();
+		}
+		Effect::AilmentCure(ref cure) =>
+		{
+			let member = &battle.state().parties()[cure.party].active_member(cure.active);
+			println!("{} recovered from its ailment!", member.member.nick());
+			terminal::wait();
+		}
+		Effect::AilmentTick(_) | Effect::VolatileRemove(_) | Effect::EffortGain(_) =>
+		{
+			// Ignore.
+		}
+		Effect::VolatileAdd(ref volatile_add) =>
+		{
+			let member = &battle.state().parties()[volatile_add.party].active_member(
+				volatile_add.active);
+			println!("{} is affected by {:?}!", member.member.nick(),
+				volatile_add.volatile.volatile_type());
+			terminal::wait();
+		}
+		Effect::Heal(ref heal) =>
+		{
+			let member = &battle.state().parties()[heal.party].active_member(heal.active);
+			println!("{} regained health!", member.member.nick());
+			terminal::wait();
+		}
+		Effect::ItemConsume(ref item_consume) =>
+		{
+			let member = &battle.state().parties()[item_consume.party].active_member(
+				item_consume.active);
+			println!("{} used its {}!", member.member.nick(), item_consume.item.item().name());
+			terminal::wait();
+		}
+		Effect::ItemUse(ref item_use) =>
+		{
+			println!("Party {} used a {}!", item_use.party, item_use.item.item().name());
+			terminal::wait();
+		}
+		Effect::HealthRestore(ref restore) =>
+		{
+			let member = battle.state().parties()[restore.party].member(restore.member);
+			println!("{} regained health!", member.nick());
+			terminal::wait();
+		}
+		Effect::AilmentRemove(ref remove) =>
+		{
+			let member = battle.state().parties()[remove.party].member(remove.member);
+			println!("{} recovered from its ailment!", member.nick());
+			terminal::wait();
+		}
+		Effect::LimitRestore(ref restore) =>
+		{
+			let member = battle.state().parties()[restore.party].member(restore.member);
+			let attack = member.attacks()[restore.attack].attack();
+			println!("{} restored the uses of {}!", member.nick(), attack.name());
+			terminal::wait();
+		}
+		Effect::RecruitShake(_) =>
+		{
+			println!("The ball shook...");
+			terminal::wait();
+		}
+		Effect::RecruitSuccess(ref success) =>
+		{
+			let member = &battle.state().parties()[success.target_party].active_member(
+				success.target_active);
+			println!("{} was recruited!", member.member.nick());
+			terminal::wait();
+		}
+		Effect::RecruitFailure(ref failure) =>
+		{
+			let member = &battle.state().parties()[failure.party].active_member(failure.active);
+			println!("{} broke free!", member.member.nick());
+			terminal::wait();
+		}
+		Effect::EscapeSuccess(ref success) =>
+		{
+			if success.party == 0
+			{
+				println!("Got away safely!");
+			}
+			else
+			{
+				println!("The wild monster fled!");
+			}
+			terminal::wait();
+		}
+		Effect::EscapeFailure(_) =>
+		{
+			println!("Can't escape!");
+			terminal::wait();
+		}
 		Effect::None(ref reason) =>
 		{
 			match *reason
@@ -333,9 +437,14 @@ fn battle_execute_effect(battle: &Battle)
 					println!("It missed!");
 					terminal::wait();
 				}
-				NoneReason::Escape =>
+				NoneReason::Ailment(ailment) =>
 				{
-					println!("You escaped!");
+					println!("It can't move because of {:?}!", ailment);
+					terminal::wait();
+				}
+				NoneReason::Flinch =>
+				{
+					println!("It flinched and couldn't move!");
 					terminal::wait();
 				}
 			}
@@ -418,7 +527,8 @@ fn battle_execute(battle: &mut Battle) -> bool
 						println!("{} used {}.", nick, attack_name);
 						terminal::wait();
 					}
-					CommandType::Switch(_) | CommandType::Escape(_) | CommandType::Turn =>
+					CommandType::Item(_) | CommandType::Recruit(_) | CommandType::Switch(_) |
+						CommandType::Escape(_) | CommandType::Turn =>
 					{
 						// Ignore.
 					}
@@ -436,15 +546,14 @@ fn battle_execute(battle: &mut Battle) -> bool
 			{
 				unreachable!();
 			}
-			BattleExecution::Finished(side) =>
+			BattleExecution::Finished(outcome) =>
 			{
-				if side == 0
-				{
-					println!("You won!");
-				}
-				else
+				match outcome
 				{
-					println!("You lost...");
+					BattleOutcome::Win(0) => println!("You won!"),
+					BattleOutcome::Win(_) => println!("You lost..."),
+					BattleOutcome::Draw => println!("It's a draw!"),
+					BattleOutcome::Escape(_) => {}
 				}
 				return false;
 			}
@@ -454,6 +563,16 @@ fn battle_execute(battle: &mut Battle) -> bool
 
 pub fn main()
 {
+	terminal::clear();
+	println!("{:^20}{:^20}", "1) Wild battle", "2) Trainer battle");
+	println!("");
+	println!("What kind of battle?");
+	let enemy_kind = match terminal::input_range(2)
+	{
+		1 => PartyKind::Wild,
+		_ => PartyKind::Trainer,
+	};
+
 	// Initialize parties.
 	let mut party_enemy =
 	[
@@ -477,10 +596,12 @@ pub fn main()
 		Monster::new(SpeciesType::Bulbasaur, 5),
 	];
 	
+	let mut enemy = Party::new(&mut party_enemy, 1, 2, false);
+	enemy.kind_set(enemy_kind);
 	let battle_data = vec!
 	[
 		Party::new(&mut party_self, 0, 2, true),
-		Party::new(&mut party_enemy, 1, 2, false),
+		enemy,
 	];
 	let mut battle = Battle::new(battle_data).unwrap();
 
@@ -535,7 +656,7 @@ pub fn main()
 
 						println!("Choose a target");
 
-						let target = battle_prompt_target(&battle);
+						let target = battle_prompt_target(&battle, active, input - 1);
 						if let Some((target_party, target_member)) = target
 						{
 							let err = battle.command_add_attack(0, active, input - 1, target_party, target_member);
@@ -584,9 +705,16 @@ pub fn main()
 					active -= 1;
 					continue;
 				}
-				// TODO: Escaping should be a command after the command checks are fixed.
-				println!("Ran away safely.");
-				break;
+				let err = battle.command_add_escape(0);
+				if err != BattleError::None
+				{
+					display_error(err);
+					terminal::wait();
+					continue;
+				}
+
+				// Escaping replaces the commands of every active member.
+				active = battle.state().parties()[0].active_count() - 1;
 			}
 			_ =>
 			{
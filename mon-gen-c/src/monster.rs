@@ -2,7 +2,8 @@ use std::os::raw::c_char;
 use std::ffi::CStr;
 use num::FromPrimitive;
 
-use mon_gen::monster::{Monster, LevelType, PersonalityType, NatureId, StatType};
+use mon_gen::ability::AbilityId;
+use mon_gen::monster::{Monster, LevelType, PersonalityType, NatureId, StatType, TrainerIdType};
 use mon_gen::species::{SpeciesId, SpeciesType, FormId, GenderId};
 
 #[no_mangle]
@@ -61,6 +62,12 @@ pub unsafe extern fn mon_monster_personality(monster: *mut Monster) -> Personali
 	(*monster).personality()
 }
 
+#[no_mangle]
+pub unsafe extern fn mon_monster_shiny(monster: *mut Monster, trainer: TrainerIdType) -> bool
+{
+	(*monster).shiny(trainer)
+}
+
 #[no_mangle]
 pub unsafe extern fn mon_monster_gender(monster: *mut Monster) -> GenderId
 {
@@ -73,6 +80,12 @@ pub unsafe extern fn mon_monster_nature(monster: *mut Monster) -> NatureId
 	(*monster).nature() as NatureId
 }
 
+#[no_mangle]
+pub unsafe extern fn mon_monster_ability(monster: *mut Monster) -> AbilityId
+{
+	(*monster).ability() as AbilityId
+}
+
 #[no_mangle]
 pub unsafe extern fn mon_monster_stat_health(monster: *mut Monster) -> StatType
 {
@@ -0,0 +1,26 @@
+use base::util::as_rust_str;
+
+/// A trait of a `Monster` that takes effect on its own in `Battle`.
+#[derive(Debug)]
+pub struct AbilityMeta
+{
+	/// The default name of the ability as a raw C compatible string.
+	pub name: &'static [u8],
+
+	/// A short description of the ability as a raw C compatible string.
+	pub description: &'static [u8],
+}
+
+impl AbilityMeta
+{
+	/// The default name of the ability.
+	pub fn name(&self) -> &'static str
+	{
+		as_rust_str(self.name)
+	}
+	/// A short description of the ability.
+	pub fn description(&self) -> &'static str
+	{
+		as_rust_str(self.description)
+	}
+}
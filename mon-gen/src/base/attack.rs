@@ -23,7 +23,7 @@ impl Target
 	/// The attack affects targets adjacent to itself.
 	pub const RANGE_ADJACENT: TargetType = 0b000100;
 
-	/// The attack affects targets opposite of itself.
+	/// The attack affects targets that are not adjacent to itself.
 	pub const RANGE_OPPOSITE: TargetType = 0b001000;
 
 	/// The attack affects is capable of hitting any target.
@@ -33,12 +33,7 @@ impl Target
 	pub const TARGET_SELF: TargetType    = 0b010000;
 
 	/// The attack affects all possible targets.
-	pub const MULTI: TargetType          = 0b010000;
-
-	pub fn is_adjacent_with(to: usize, from: usize) -> bool
-	{
-		to == from || (to > 0 && to - 1 == from) || (to < usize::max_value() && to + 1 == from)
-	}
+	pub const MULTI: TargetType          = 0b100000;
 }
 
 /// A single action used by a `Monster` in `Battle`.
@@ -60,8 +55,8 @@ pub struct AttackMeta
 	/// The base power for the move.
 	pub power: PowerType,
 
-	/// The accuracy value for using this move.
-	pub accuracy: AccuracyType,
+	/// The chance from 0 to 1 of the attack hitting before modifiers, or `None` if it never misses.
+	pub accuracy: Option<AccuracyType>,
 
 	/// The limit that this move can be used.
 	pub limit: LimitType,
@@ -1,12 +1,24 @@
 use std::collections::HashMap;
 use std::io;
 
-use base::attack::Target;
-use base::command::{CommandType, CommandAttack, CommandSwitch, CommandEscape, CommandRetreat};
+use base::command::{CommandType, CommandAttack, CommandItem, CommandRecruit, CommandSwitch,
+	CommandEscape, CommandRetreat};
 use base::effect::Effect;
+use base::monster::Monster;
 use base::queue::BattleQueue;
-use base::party::Party;
+use base::party::{Party, PartyKind};
+use base::replay::BattleReplay;
 use base::runner::{BattleRunner, BattleExecution, BattlePartyMember, BattleState};
+use base::target::FieldLayout;
+use base::volatile::VolatileType;
+use calculate::common::target_valid;
+use calculate::effects::hit_chance;
+use gen::attack_list::AttackType;
+use gen::item_list::ItemType;
+use gen::locations::{Location, SubLocation};
+use gen::monster::RecruitMethod;
+use types::attack::AccuracyType;
+use types::generic::DateType;
 
 /// Indicates an error adding a command to a battle.
 #[derive(Debug, PartialEq)]
@@ -14,18 +26,38 @@ pub enum BattleError
 {
 	/// There was no error.
 	None,
-	/// Occurs when the battle turn is in progress. New commands cannot be added.
+	/// Occurs when the battle turn is in progress. New commands cannot be added and the layout
+	/// cannot be changed.
 	Rejected,
 	/// Occurs when the chosen attack is unable to be used due to having reached the use limit.
 	AttackLimit,
 	/// Occurs when the chosen attack is unable to target the chosen party and respective member.
 	AttackTarget,
+	/// Occurs when the chosen attack cannot be learned by the chosen member.
+	AttackLearn,
 	/// Occurs when a switch cannot occur because the target is the same as what is being switched.
 	SwitchActive,
 	/// Occurs when a switch cannot occur because the target has no health.
 	SwitchHealth,
 	/// Occurs when a switch cannot occur because the target has already been queued to switch.
 	SwitchQueued,
+	/// Occurs when a switch cannot occur because the active member is trapped.
+	SwitchTrapped,
+	/// Occurs when the chosen item cannot be used from the bag during battle.
+	ItemUnusable,
+	/// Occurs when the chosen item cannot be used on the target because it has no health or the
+	/// item would have no effect on it.
+	ItemTarget,
+	/// Occurs when there are none of the chosen item left in the bag, including queued uses.
+	ItemEmpty,
+	/// Occurs when the chosen member to recruit is on the same side or has no health.
+	RecruitTarget,
+	/// Occurs when the chosen member to recruit has already been recruited by someone else.
+	RecruitOwned,
+	/// Occurs when the chosen member to recruit belongs to a party that is not wild.
+	RecruitKind,
+	/// Occurs when escaping from a battle against a party that is not wild.
+	EscapeKind,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -53,14 +85,30 @@ impl<'a> Battle<'a>
 	pub fn new(parties: Vec<Party<'a>>) -> Result<Self, io::Error>
 	{
 		let queue = BattleQueue::new(&parties);
-		Ok(Battle
+		Ok(Battle::with_runner(BattleRunner::new(parties)?, queue))
+	}
+
+	/// Generates a new battle object with an RNG seeded by `seed` and empty command history.
+	///
+	/// Battles created with the same seed, parties and commands will always have the same damage
+	/// rolls, critical hits and misses. This is useful for reproducing bugs.
+	///
+	pub fn with_seed(parties: Vec<Party<'a>>, seed: usize) -> Self
+	{
+		let queue = BattleQueue::new(&parties);
+		Battle::with_runner(BattleRunner::with_seed(parties, seed), queue)
+	}
+
+	fn with_runner(runner: BattleRunner<'a>, queue: BattleQueue) -> Self
+	{
+		Battle
 		{
-			runner: BattleRunner::new(parties)?,
+			runner: runner,
 			queue: queue,
 			processing: BattleInputState::Ready,
 			post_switch: HashMap::new(),
 			// switch_waiting: None,
-		})
+		}
 	}
 
 	pub fn state(&self) -> &BattleState
@@ -68,6 +116,81 @@ impl<'a> Battle<'a>
 		self.runner.state()
 	}
 
+	/// Consumes the battle and returns the members of each party in their original order.
+	///
+	/// Experience, health and attack limits gained or lost in battle are kept. This is how members
+	/// of parties created with `Party::with_owned` are taken back after the battle is finished.
+	///
+	pub fn into_members(self) -> Vec<Vec<Monster>>
+	{
+		self.runner.into_parties().into_iter().map(|party| party.into_members()).collect()
+	}
+
+	/// Sets the location the battle takes place at. Recruited members remember this location.
+	pub fn location_set(&mut self, location: Location)
+	{
+		self.runner.location_set(location);
+	}
+
+	/// Sets the place within the location the battle takes place at, such as grass or water.
+	pub fn sub_location_set(&mut self, sub_location: SubLocation)
+	{
+		self.runner.sub_location_set(sub_location);
+	}
+
+	/// Sets the date the battle takes place on. Recruited members remember this date.
+	pub fn date_set(&mut self, date: DateType)
+	{
+		self.runner.date_set(date);
+	}
+
+	/// Sets how active members are arranged on the field, which decides who attacks can target.
+	///
+	/// The layout can only be changed before any commands are added, otherwise it is rejected.
+	///
+	pub fn layout_set(&mut self, layout: FieldLayout) -> BattleError
+	{
+		if self.processing != BattleInputState::Ready || !self.queue.empty() ||
+			self.runner.replay().command_count() != 0
+		{
+			return BattleError::Rejected;
+		}
+		self.runner.layout_set(layout);
+		BattleError::None
+	}
+
+	/// Teaches an attack to a party member, such as one listed by a `LevelUp` effect.
+	///
+	/// Attacks can only be learned while waiting for input before any commands are added. The
+	/// attack is learned into a free slot or by replacing the attack at index `replace`. See
+	/// `Monster::attack_learn` for when learning fails.
+	///
+	pub fn member_attack_learn(&mut self, party: usize, member: usize, attack: AttackType,
+		replace: Option<usize>) -> BattleError
+	{
+		debug_assert!(party <= self.state().parties().len());
+		debug_assert!(member <= self.state().parties()[party].member_count());
+
+		if self.processing != BattleInputState::Ready || !self.queue.empty()
+		{
+			BattleError::Rejected
+		}
+		else if self.runner.member_attack_learn(party, member, attack, replace)
+		{
+			BattleError::None
+		}
+		else
+		{
+			BattleError::AttackLearn
+		}
+	}
+
+	/// The recorded seed and command history for this battle.
+	pub fn replay(&self) -> &BattleReplay
+	{
+		self.runner.replay()
+	}
+
 	pub fn current_command(&self) -> &CommandType
 	{
 		self.runner.current_command()
@@ -78,6 +201,20 @@ impl<'a> Battle<'a>
 		self.runner.current_effect()
 	}
 
+	/// The chance from 0 to 1 of an attack of an active member hitting the target if it were used
+	/// now.
+	///
+	/// This takes the accuracy and evasion modifiers of both members into account, so it can be
+	/// shown while choosing commands. Attacks that never miss always have a chance of 1.
+	///
+	pub fn hit_chance(&self, party: usize, active: usize, attack: usize, target_party: usize,
+		target_active: usize) -> AccuracyType
+	{
+		let parties = self.state().parties();
+		hit_chance(&parties[party].active_member(active), attack,
+			&parties[target_party].active_member(target_active))
+	}
+
 	/// Adds a command for attacking another party's member.
 	pub fn command_add_attack(&mut self, party: usize, active: usize, attack: usize,
 		target_party: usize, target_active: usize) -> BattleError
@@ -97,30 +234,8 @@ impl<'a> Battle<'a>
 		{
 			return BattleError::AttackLimit;
 		}
-
-		// TODO: This should be side check, not party check.
-		let same_party = party == target_party;
-		if (active_attack.attack().target & Target::SIDE_ENEMY) == 0 && !same_party
-		{
-			return BattleError::AttackTarget;
-		}
-		if (active_attack.attack().target & Target::SIDE_ALLY) == 0 && same_party
-		{
-			return BattleError::AttackTarget;
-		}
-
-		let is_adjacent = Target::is_adjacent_with(active, target_active);
-		if (active_attack.attack().target & Target::RANGE_ADJACENT) == 0 && is_adjacent
-		{
-			return BattleError::AttackTarget;
-		}
-		if (active_attack.attack().target & Target::RANGE_OPPOSITE) == 0 && !is_adjacent
-		{
-			return BattleError::AttackTarget;
-		}
-
-		let same_member = active == target_active;
-		if (active_attack.attack().target & Target::TARGET_SELF) == 0 && same_party && same_member
+		if !target_valid(self.runner.state(), active_attack.attack().target, party, active,
+			target_party, target_active)
 		{
 			return BattleError::AttackTarget;
 		}
@@ -162,6 +277,10 @@ impl<'a> Battle<'a>
 		{
 			BattleError::SwitchActive
 		}
+		else if self.state().parties()[party].active_member(active).volatile_has(VolatileType::Trap)
+		{
+			BattleError::SwitchTrapped
+		}
 		else
 		{
 			for active_index in 0..self.state().parties()[party].active_count()
@@ -189,18 +308,134 @@ impl<'a> Battle<'a>
 		}
 	}
 
+	/// Adds a command for using an item from the bag on a party member instead of attacking.
+	///
+	/// The requested `target` party member must be referenced by member index, even if it is an
+	/// active member.
+	///
+	pub fn command_add_item(&mut self, party: usize, active: usize, item: ItemType, target: usize)
+		-> BattleError
+	{
+		debug_assert!(party <= self.state().parties().len());
+		debug_assert!(active <= self.state().parties()[party].active_count());
+		debug_assert!(target <= self.state().parties()[party].member_count());
+
+		if self.processing != BattleInputState::Ready
+		{
+			return BattleError::Rejected;
+		}
+		if !item.usable()
+		{
+			return BattleError::ItemUnusable;
+		}
+		if self.state().parties()[party].member(target).health() == 0
+		{
+			return BattleError::ItemTarget;
+		}
+
+		// Other active members may already be queued to use the same item.
+		let mut queued = 0;
+		for active_index in 0..self.state().parties()[party].active_count()
+		{
+			if let Some(command) = self.queue.command_get(party, active_index)
+			{
+				if let CommandType::Item(ref item_command) = *command
+				{
+					if item_command.item == item && item_command.member != active
+					{
+						queued += 1;
+					}
+				}
+			}
+		}
+		if self.state().parties()[party].bag_count(item) <= queued
+		{
+			return BattleError::ItemEmpty;
+		}
+
+		let command_item = CommandItem
+		{
+			party: party,
+			member: active,
+			item: item,
+			target: target,
+		};
+		if !self.runner.item_effective(&command_item)
+		{
+			return BattleError::ItemTarget;
+		}
+		self.queue.command_add(CommandType::Item(command_item), party, active);
+		BattleError::None
+	}
+
+	/// Adds a command for recruiting an active member of another party instead of attacking.
+	///
+	/// When recruiting succeeds, the target is marked as recruited with the given method and the
+	/// battle is finished.
+	///
+	pub fn command_add_recruit(&mut self, party: usize, active: usize, method: RecruitMethod,
+		target_party: usize, target_active: usize) -> BattleError
+	{
+		debug_assert!(party <= self.state().parties().len());
+		debug_assert!(active <= self.state().parties()[party].active_count());
+		debug_assert!(target_party <= self.state().parties().len());
+		debug_assert!(target_active <= self.state().parties()[target_party].active_count());
+
+		if self.processing != BattleInputState::Ready
+		{
+			return BattleError::Rejected;
+		}
+
+		{
+			let target = self.state().parties()[target_party].active_member(target_active).member;
+			if self.state().parties()[party].side() == self.state().parties()[target_party].side() ||
+				target.health() == 0
+			{
+				return BattleError::RecruitTarget;
+			}
+			if target.recruited()
+			{
+				return BattleError::RecruitOwned;
+			}
+			if self.state().parties()[target_party].kind() != PartyKind::Wild
+			{
+				return BattleError::RecruitKind;
+			}
+		}
+
+		let command_recruit = CommandRecruit
+		{
+			party: party,
+			member: active,
+			method: method,
+			target_party: target_party,
+			target_member: target_active,
+		};
+		self.queue.command_add(CommandType::Recruit(command_recruit), party, active);
+		BattleError::None
+	}
+
 	/// Adds a party central command for escaping which prematurely ends the battle.
 	///
-	/// This command will remove any commands attached to individual party members.
+	/// This command will remove any commands attached to individual party members. Battles
+	/// against trainer or link parties cannot be escaped from.
 	///
 	pub fn command_add_escape(&mut self, party: usize) -> BattleError
 	{
 		debug_assert!(party <= self.state().parties().len());
 
+		let side = self.state().parties()[party].side();
 		if self.processing != BattleInputState::Ready
 		{
 			BattleError::Rejected
 		}
+		else if self.state().parties().iter().any(|other|
+		{
+			other.side() != side && other.kind() != PartyKind::Wild
+		})
+		{
+			BattleError::EscapeKind
+		}
 		else
 		{
 			self.queue.command_add_party(CommandType::Escape(CommandEscape
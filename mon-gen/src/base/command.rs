@@ -1,9 +1,13 @@
 // use base::battle::Battle;
 use base::party::Party;
 use base::monster::MonsterAttack;
-use base::effect::{Effect, Switch, NoneReason};
+use base::effect::{Effect, ItemUse, Switch, NoneReason};
 use base::battle::Battle;
 use base::runner::{BattleFlags, BattleFlagsType, BattleState, BattleEffects};
+use calculate::escape;
+use calculate::recruit;
+use gen::item_list::ItemType;
+use gen::monster::RecruitMethod;
 
 use rand::Rng;
 
@@ -26,6 +30,16 @@ impl CommandType
 					Ordering::Greater
 				}
 			}
+			CommandType::Item(_) | CommandType::Recruit(_) =>
+			{
+				// Items and recruiting are used after escapes and switches but before any attack.
+				match *other
+				{
+					CommandType::Item(_) | CommandType::Recruit(_) => self.user().cmp(&other.user()),
+					CommandType::Switch(_) | CommandType::Escape(_) => Ordering::Greater,
+					CommandType::Attack(_) | CommandType::Turn => Ordering::Less,
+				}
+			}
 			CommandType::Switch(ref switch_command_self) =>
 			{
 				if let CommandType::Switch(ref switch_command_other) = *other
@@ -70,11 +84,12 @@ impl CommandType
 	}
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum CommandType
 {
 	Attack(CommandAttack),
-	// Item(CommandItem),
+	Item(CommandItem),
+	Recruit(CommandRecruit),
 	Switch(CommandSwitch),
 	Escape(CommandEscape),
 	Turn,
@@ -88,6 +103,17 @@ pub struct CommandEscape
 
 impl CommandType
 {
+	// The party and active party member that is using an item or recruiting.
+	fn user(&self) -> (usize, usize)
+	{
+		match *self
+		{
+			CommandType::Item(ref command) => (command.party, command.member),
+			CommandType::Recruit(ref command) => (command.party, command.member),
+			_ => unreachable!(),
+		}
+	}
+
 	pub fn effects<R: Rng>(&self, effects: &mut BattleEffects, state: &BattleState, rng: &mut R)
 	{
 		match *self
@@ -102,6 +128,19 @@ impl CommandType
 				};
 				attack.effects(effects, attack_command, attack_command.party, state, rng);
 			}
+			CommandType::Item(ref item_command) =>
+			{
+				effects.effect_add(Effect::ItemUse(ItemUse
+				{
+					party: item_command.party,
+					item: item_command.item,
+				}));
+				item_command.item.use_effects(effects, item_command, state, rng);
+			}
+			CommandType::Recruit(ref recruit_command) =>
+			{
+				recruit::effects(effects, recruit_command, state, rng);
+			}
 			CommandType::Switch(ref switch_command) =>
 			{
 				let switch = Switch
@@ -112,9 +151,9 @@ impl CommandType
 				};
 				effects.effect_add(Effect::Switch(switch));
 			}
-			CommandType::Escape(_) =>
+			CommandType::Escape(ref escape_command) =>
 			{
-				effects.effect_add(Effect::None(NoneReason::Escape));
+				escape::effects(effects, escape_command, state, rng);
 			}
 			CommandType::Turn =>
 			{
@@ -184,6 +223,33 @@ impl CommandAttack
 	}
 }
 
+/// Uses an item from the bag of `party` on the party member at index `target`.
+///
+/// Using an item takes up the turn of the active member at `member`.
+///
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct CommandItem
+{
+	pub party: usize,
+	pub member: usize,
+	pub item: ItemType,
+	pub target: usize,
+}
+
+/// Attempts to recruit the active member `target_member` of `target_party` using `method`.
+///
+/// Recruiting takes up the turn of the active member at `member`.
+///
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct CommandRecruit
+{
+	pub party: usize,
+	pub member: usize,
+	pub method: RecruitMethod,
+	pub target_party: usize,
+	pub target_member: usize,
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub struct CommandSwitch
 {
@@ -1,14 +1,21 @@
+use types::attack::LimitType;
 use types::monster::StatType;
+use base::monster::{MonsterLevelUp, STAT_COUNT};
 use base::statmod::StatModifiers;
+use base::volatile::{Volatile, VolatileType};
 
 use types::monster::ExperienceType;
+use types::species::StatYieldType;
 
 use base::runner::BattleFlagsType;
 use base::runner::{BattleEffects, BattleState};
 
 use calculate::lingering::LingeringType;
+use gen::attack::Ailment;
+use gen::item_list::ItemType;
+use gen::monster::RecruitMethod;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Effect
 {
 	Damage(Damage),
@@ -16,17 +23,34 @@ pub enum Effect
 	Retreat(Retreat),
 	Modifier(Modifier),
 	ExperienceGain(ExperienceGain),
+	EffortGain(EffortGain),
+	LevelUp(LevelUp),
 	FlagsChange(FlagsChange),
 	LingeringAdd(LingeringAdd),
 	LingeringChange(LingeringChange),
-	// Status(StatusId),
+	AilmentInflict(AilmentInflict),
+	AilmentCure(AilmentCure),
+	AilmentTick(AilmentTick),
+	VolatileAdd(VolatileAdd),
+	VolatileRemove(VolatileRemove),
+	Heal(Heal),
+	ItemConsume(ItemConsume),
+	ItemUse(ItemUse),
+	HealthRestore(HealthRestore),
+	AilmentRemove(AilmentRemove),
+	LimitRestore(LimitRestore),
+	RecruitShake(RecruitShake),
+	RecruitSuccess(RecruitSuccess),
+	RecruitFailure(RecruitFailure),
+	EscapeSuccess(EscapeSuccess),
+	EscapeFailure(EscapeFailure),
 	// Ability(AbilityId),
 	// Miss,
 	// ,
 	None(NoneReason),
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Damage
 {
 	pub party: usize, //
@@ -59,7 +83,7 @@ impl Damage
 	}
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DamageMeta
 {
 	pub amount: StatType, //
@@ -68,7 +92,7 @@ pub struct DamageMeta
 	// pub recoil: bool, //
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Switch
 {
 	pub party: usize,
@@ -76,14 +100,14 @@ pub struct Switch
 	pub target: usize,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Retreat
 {
 	pub party: usize,
 	pub active: usize,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Modifier
 {
 	party: usize,
@@ -116,7 +140,7 @@ impl Modifier
 	}
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ExperienceGain
 {
 	pub party: usize,
@@ -140,16 +164,45 @@ impl ExperienceGain
 	}
 }
 
-#[derive(Debug, PartialEq)]
+/// A party member gained effort values from the yield of a defeated member.
+///
+/// Effort values are ordered like `MonsterLevelUp::stats`. Only the effort values gained within the
+/// limits are listed. Members that cannot gain any do not get this effect.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct EffortGain
+{
+	pub party: usize,
+	pub member: usize,
+	pub efforts: [StatYieldType; STAT_COUNT],
+}
+
+/// A party member gained levels from experience, possibly with new attacks to learn.
+///
+/// New attacks can be learned with `Battle::member_attack_learn` once the battle is waiting for
+/// commands again, or with `Monster::attack_learn` once the battle is over.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct LevelUp
+{
+	pub party: usize,
+	pub member: usize,
+	pub level_up: MonsterLevelUp,
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum NoneReason
 {
 	None,
 	Miss,
-	Escape,
 	Turn,
+	/// The party member could not act because of its ailment.
+	Ailment(Ailment),
+	/// The party member flinched and could not act.
+	Flinch,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct FlagsChange
 {
 	pub flags: BattleFlagsType,
@@ -183,14 +236,141 @@ pub trait Lingering
 	}
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct LingeringAdd
 {
 	pub lingering: LingeringType,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct LingeringChange
 {
 	pub index: usize,
 }
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AilmentInflict
+{
+	pub party: usize,
+	pub active: usize,
+	pub ailment: Ailment,
+	// Turns left until the ailment wears off, for ailments that do so.
+	pub turns: u8,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AilmentCure
+{
+	pub party: usize,
+	pub active: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AilmentTick
+{
+	pub party: usize,
+	pub active: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VolatileAdd
+{
+	pub party: usize,
+	pub active: usize,
+	pub volatile: Volatile,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VolatileRemove
+{
+	pub party: usize,
+	pub active: usize,
+	pub volatile: VolatileType,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Heal
+{
+	pub party: usize,
+	pub active: usize,
+	pub amount: StatType,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ItemConsume
+{
+	pub party: usize,
+	pub active: usize,
+	// The held item that was used up.
+	pub item: ItemType,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ItemUse
+{
+	pub party: usize,
+	// The item that was taken from the bag.
+	pub item: ItemType,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HealthRestore
+{
+	pub party: usize,
+	pub member: usize,
+	pub amount: StatType,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AilmentRemove
+{
+	pub party: usize,
+	pub member: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LimitRestore
+{
+	pub party: usize,
+	pub member: usize,
+	pub attack: usize,
+	pub amount: LimitType,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecruitShake
+{
+	pub party: usize,
+	pub active: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecruitSuccess
+{
+	// The party that recruited the target.
+	pub party: usize,
+	pub target_party: usize,
+	pub target_active: usize,
+	pub method: RecruitMethod,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecruitFailure
+{
+	pub party: usize,
+	pub active: usize,
+}
+
+/// The party escaped, ending the battle.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EscapeSuccess
+{
+	pub party: usize,
+}
+
+/// The party failed to escape. Each failed attempt makes the next one likelier to succeed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EscapeFailure
+{
+	pub party: usize,
+}
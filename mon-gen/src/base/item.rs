@@ -0,0 +1,26 @@
+use base::util::as_rust_str;
+
+/// An object that can be held by a `Monster` to take effect on its own in `Battle`.
+#[derive(Debug)]
+pub struct ItemMeta
+{
+	/// The default name of the item as a raw C compatible string.
+	pub name: &'static [u8],
+
+	/// A short description of the item as a raw C compatible string.
+	pub description: &'static [u8],
+}
+
+impl ItemMeta
+{
+	/// The default name of the item.
+	pub fn name(&self) -> &'static str
+	{
+		as_rust_str(self.name)
+	}
+	/// A short description of the item.
+	pub fn description(&self) -> &'static str
+	{
+		as_rust_str(self.description)
+	}
+}
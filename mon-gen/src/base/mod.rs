@@ -3,13 +3,18 @@ pub mod species;
 pub mod monster;
 pub mod battle;
 pub mod attack;
+pub mod ability;
+pub mod item;
 pub mod target;
 pub mod party;
 pub mod statmod;
 pub mod command;
 pub mod effect;
 pub mod util;
+pub mod volatile;
+pub mod serialize;
 
 pub mod runner;
 pub mod replay;
+pub mod player;
 pub mod queue;
\ No newline at end of file
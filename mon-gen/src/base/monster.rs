@@ -1,23 +1,52 @@
 //! An instance of a species.
+use std::cmp::min;
+use std::error;
 use std::ffi::{CStr, CString};
+use std::fmt;
+use std::io;
+use std::io::{Read, Write};
 use std::os::raw::c_char;
 
-use rand::{random, thread_rng};
+use num::FromPrimitive;
+use rand::{Rng, SeedableRng, StdRng, thread_rng};
 use rand::distributions::{IndependentSample, Range};
+use toml;
 
+use base::serialize::*;
+use base::species::EvolutionTrigger;
 use base::util::as_rust_str_from;
+use calculate::personality;
 use calculate::statistics;
+use gen::ability_list::AbilityType;
+use gen::attack::Ailment;
 use gen::attack_list::AttackType;
 use gen::element::Element;
 use gen::gender::Gender;
+use gen::item_list::ItemType;
+use gen::locations::{Location, SubLocation};
 use gen::monster::{Nature, RecruitMethod};
 use gen::species_list::SpeciesType;
-use types::monster::{LevelType, PersonalityType, StatType, StatIndividualType, ExperienceType};
-use types::species::{FormId, StatBaseType, StatYieldType};
+use types::attack::{AilmentId, AttackId};
+use types::gender::GenderId;
+use types::generic::{DateType, LocationId, SubLocationId};
+use types::item::ItemId;
+use types::monster::{LevelType, PersonalityType, StatType, StatIndividualType, ExperienceType,
+	NatureId, RecruitMethodId, TrainerIdType};
+use types::species::{AbilityId, FormId, FriendshipType, SpeciesId, StatBaseType,
+	StatYieldType};
 
 /// The limit on the number of attacks a Monster can have.
 pub const ATTACK_LIMIT: usize = 4;
 
+/// The number of statistics stored for effort and individual values.
+pub const STAT_COUNT: usize = 6;
+
+/// The limit on the effort value of a single statistic.
+pub const EFFORT_LIMIT: StatYieldType = 252;
+
+/// The limit on the sum of the effort values of every statistic.
+pub const EFFORT_TOTAL_LIMIT: u16 = 510;
+
 pub type LimitUpgradeType = u8;
 
 pub const LIMIT_BOOST: f32 = 0.2;
@@ -25,7 +54,7 @@ pub const LIMIT_BOOST: f32 = 0.2;
 use types::attack::LimitType;
 use base::attack::AttackMeta;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct MonsterAttack
 {
 	attack_type: AttackType,
@@ -56,6 +85,10 @@ impl MonsterAttack
 	{
 		self.limit_left = self.limit_left.saturating_sub(amount);
 	}
+	pub fn limit_left_restore(&mut self, amount: LimitType)
+	{
+		self.limit_left = min(self.limit_left.saturating_add(amount), self.limit_max());
+	}
 	pub fn limit_left(&self) -> LimitType
 	{
 		self.limit_left
@@ -67,8 +100,96 @@ impl MonsterAttack
 	}
 }
 
+/// The result of a monster gaining experience.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonsterLevelUp
+{
+	/// The level before gaining experience.
+	pub level_from: LevelType,
+
+	/// The level after gaining experience. Equal to `level_from` if no levels were gained.
+	pub level: LevelType,
+
+	/// The statistics after gaining experience, ordered as health, attack, defense, special
+	/// attack, special defense and speed.
+	pub stats: [StatType; STAT_COUNT],
+
+	/// The attacks that became learnable, paired with the level they became learnable at.
+	///
+	/// Attacks that are already known are not included. See `Monster::attack_learn` to learn them.
+	///
+	pub attacks: Vec<(LevelType, AttackType)>,
+}
+
+impl MonsterLevelUp
+{
+	/// The number of levels gained.
+	pub fn levels(&self) -> LevelType
+	{
+		self.level - self.level_from
+	}
+}
+
+/// The trainer that first recruited a monster.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trainer
+{
+	/// The displayed name of the trainer.
+	pub name: String,
+
+	/// The identifier of the trainer, which is also used to decide shininess.
+	pub id: TrainerIdType,
+}
+
+impl Trainer
+{
+	pub fn new(name: &str, id: TrainerIdType) -> Self
+	{
+		Trainer
+		{
+			name: name.to_string(),
+			id: id,
+		}
+	}
+
+	/// Saves the trainer as a TOML table.
+	pub fn to_toml(&self) -> toml::Table
+	{
+		let mut table = toml::Table::new();
+		table.insert("name".to_string(), toml::Value::String(self.name.clone()));
+		table.insert("id".to_string(), toml_int(self.id));
+		table
+	}
+
+	/// Loads a trainer saved with `to_toml`.
+	pub fn from_toml(table: &toml::Table) -> SerializeResult<Self>
+	{
+		Ok(Trainer::new(try!(toml_get_str(table, "name")), try!(toml_get(table, "id"))))
+	}
+
+	/// Saves the same values as `to_toml` in a compact binary format.
+	pub fn write_binary(&self, out: &mut Write) -> io::Result<()>
+	{
+		try!(write_bytes_u16(out, self.name.as_bytes()));
+		write_u32(out, self.id)
+	}
+
+	/// Loads a trainer saved with `write_binary`.
+	pub fn read_binary(input: &mut Read) -> SerializeResult<Self>
+	{
+		let mut name = vec![0u8; try!(read_u16(input)) as usize];
+		try!(input.read_exact(&mut name));
+		let name = try!(String::from_utf8(name).or_else(|_| invalid("trainer")));
+		Ok(Trainer
+		{
+			name: name,
+			id: try!(read_u32(input)),
+		})
+	}
+}
+
 /// An instance of a species.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Monster
 {
 	species: SpeciesType,
@@ -78,7 +199,9 @@ pub struct Monster
 	personality: PersonalityType,
 	gender: Gender,
 	nature: Nature,
+	ability: AbilityType,
 	experience: ExperienceType,
+	friendship: FriendshipType,
 	health: StatType,
 	stat_health: StatType,
 	stat_attack: StatType,
@@ -99,92 +222,35 @@ pub struct Monster
 	iv_spdefense: StatIndividualType,
 	iv_speed: StatIndividualType,
 	attacks: Vec<MonsterAttack>,
+	held_item: Option<ItemType>,
 	recruited: Option<RecruitMethod>,
-	// caught_location: Location,
+	ailment: Option<Ailment>,
+	ailment_turns: u8,
+	recruit_location: Option<Location>,
+	recruit_sub_location: Option<SubLocation>,
+	recruit_level: LevelType,
+	recruit_date: Option<DateType>,
+	trainer: Option<Trainer>,
 }
 
 impl Monster
 {
+	/// Creates a monster of the given species and level with random values.
+	///
+	/// Panics if the level is not between 1 and 100. See `MonsterBuilder` to choose values instead.
+	///
 	pub fn new(species: SpeciesType, level: LevelType) -> Self
 	{
-		let nick = unsafe
-		{
-			CStr::from_ptr(species.species().name.as_ptr() as *const c_char)
-		}
-		.to_owned();
-
-		let iv_stat = Range::new(0, 32);
-		let mut rng = thread_rng();
-
-		let mut monster = Monster
-		{
-			species: species,
-			nick: nick,
-			form: 0,
-			level: level,
-			personality: random(),
-			gender: Gender::rand(&mut rng, species.species().gender),
-			nature: random(),
-			experience: species.species().growth.experience_with_level(level),
-			health: 0,
-			stat_health: 0,
-			stat_attack: 0,
-			stat_defense: 0,
-			stat_spattack: 0,
-			stat_spdefense: 0,
-			stat_speed: 0,
-			ev_health: 0,
-			ev_attack: 0,
-			ev_defense: 0,
-			ev_spattack: 0,
-			ev_spdefense: 0,
-			ev_speed: 0,
-			iv_health: iv_stat.ind_sample(&mut rng),
-			iv_attack: iv_stat.ind_sample(&mut rng),
-			iv_defense: iv_stat.ind_sample(&mut rng),
-			iv_spattack: iv_stat.ind_sample(&mut rng),
-			iv_spdefense: iv_stat.ind_sample(&mut rng),
-			iv_speed: iv_stat.ind_sample(&mut rng),
-			attacks: Vec::new(),
-			recruited: None,
-		};
-
-		// The index at which the level is closest to.
-		let mut attack_level_index = species.species().attacks_learnable.binary_search_by(
-			|&(level, _)|
-		{
-			level.cmp(&monster.level)
-		})
-		.unwrap_or_else(|index| index - 1);
-
-		// The index at which the monster's attack list is filled.
-		let mut attack_filled_index = 0;
-		'outer: loop
-		{
-			let species = species.species();
-			let (_, attacks_forms) = species.attacks_learnable[attack_level_index];
-			let attack_list = attacks_forms[monster.form as usize];
-			for attack in attack_list
-			{
-				monster.attacks.insert(0, MonsterAttack::new(species.attacks_list[*attack]));
-				attack_filled_index += 1;
-				if attack_filled_index == ATTACK_LIMIT
-				{
-					break 'outer;
-				}
-			}
-
-			if attack_level_index == 0
-			{
-				break;
-			}
-
-			attack_level_index -= 1;
-		}
+		Monster::with_rng(species, level, &mut thread_rng())
+	}
 
-		monster.stats_recalculate();
-		monster.health_restore();
-		monster
+	/// Creates a monster like `new` with values drawn from the given random number generator.
+	///
+	/// Monsters created from generators in the same state have the same values.
+	///
+	pub fn with_rng<R: Rng>(species: SpeciesType, level: LevelType, rng: &mut R) -> Self
+	{
+		MonsterBuilder::new(species, level).build_with_rng(rng).unwrap()
 	}
 
 	pub fn species(&self) -> SpeciesType
@@ -244,11 +310,18 @@ impl Monster
 		self.level
 	}
 
+	/// The random value that the gender, ability and shininess are derived from.
 	pub fn personality(&self) -> PersonalityType
 	{
 		self.personality
 	}
 
+	/// Whether the monster is a rare shiny variant for the given trainer.
+	pub fn shiny(&self, trainer: TrainerIdType) -> bool
+	{
+		personality::shiny(self.personality, trainer)
+	}
+
 	pub fn gender(&self) -> Gender
 	{
 		self.gender
@@ -265,13 +338,30 @@ impl Monster
 //
 // 	// fn gender() -> Gender;
 // 
+	/// The trait that takes effect on its own during battle.
+	pub fn ability(&self) -> AbilityType
+	{
+		self.ability
+	}
+
+	/// Replaces the ability, even with one that the species is not normally given.
+	pub fn ability_set(&mut self, ability: AbilityType)
+	{
+		self.ability = ability;
+	}
+
 	pub fn experience_total(&self) -> ExperienceType
 	{
 		self.experience
 	}
 
-	pub fn experience_add(&mut self, amount: ExperienceType)
+	/// Adds experience, levelling up for as long as there is enough of it.
+	///
+	/// Statistics are recalculated upon levelling up and the health gained with them is added.
+	///
+	pub fn experience_add(&mut self, amount: ExperienceType) -> MonsterLevelUp
 	{
+		let level_from = self.level;
 		if self.level != 100
 		{
 			let growth = self.species().species().growth;
@@ -286,6 +376,200 @@ impl Monster
 				}
 			} 
 		}
+
+		let mut attacks = Vec::new();
+		if self.level != level_from
+		{
+			self.stats_update();
+
+			for level in (level_from + 1)..(self.level + 1)
+			{
+				for attack in self.attacks_learnable_at(level)
+				{
+					if !self.attacks.iter().any(|known| known.attack_type == attack)
+					{
+						attacks.push((level, attack));
+					}
+				}
+			}
+		}
+
+		MonsterLevelUp
+		{
+			level_from: level_from,
+			level: self.level,
+			stats: [self.stat_health, self.stat_attack, self.stat_defense, self.stat_spattack,
+				self.stat_spdefense, self.stat_speed],
+			attacks: attacks,
+		}
+	}
+
+	/// How friendly the monster is towards its trainer.
+	pub fn friendship(&self) -> FriendshipType
+	{
+		self.friendship
+	}
+
+	pub fn friendship_set(&mut self, friendship: FriendshipType)
+	{
+		self.friendship = friendship;
+	}
+
+	/// The species this monster evolves into from its current level or friendship, if any.
+	///
+	/// Level and friendship evolutions happen upon levelling up, so this should be checked after
+	/// `experience_add`.
+	///
+	pub fn evolution_pending(&self) -> Option<SpeciesType>
+	{
+		self.evolution_find(|trigger| match trigger
+		{
+			EvolutionTrigger::Level(level) => self.level >= level,
+			EvolutionTrigger::Friendship(friendship) => self.friendship >= friendship,
+			_ => false,
+		})
+	}
+
+	/// The species this monster evolves into when the given item is used on it, if any.
+	pub fn evolution_item(&self, item: ItemType) -> Option<SpeciesType>
+	{
+		self.evolution_find(|trigger| trigger == EvolutionTrigger::Item(item))
+	}
+
+	/// The species this monster evolves into when it is traded, if any.
+	pub fn evolution_trade(&self) -> Option<SpeciesType>
+	{
+		self.evolution_find(|trigger| trigger == EvolutionTrigger::Trade)
+	}
+
+	/// Evolves into the given species, keeping everything that does not depend on the species.
+	///
+	/// Statistics are recalculated and health that was missing before evolving remains missing. A
+	/// nick that is the name of the previous species is renamed to the new species. The ability is
+	/// replaced by the one in the same slot of the new species.
+	///
+	pub fn evolve(&mut self, species: SpeciesType)
+	{
+		let previous = self.species.species();
+		let evolved = species.species();
+
+		if self.nick.as_bytes_with_nul() == previous.name
+		{
+			self.nick = Monster::species_nick(species);
+		}
+
+		let ability = self.ability;
+		if let Some(index) = previous.abilities.iter().position(|slot| *slot == ability)
+		{
+			self.ability = evolved.abilities[min(index, evolved.abilities.len() - 1)];
+		}
+		else if let Some(index) = previous.abilities_hidden.iter().position(|slot| *slot == ability)
+		{
+			self.ability = match evolved.abilities_hidden.len()
+			{
+				0 => evolved.abilities[0],
+				count => evolved.abilities_hidden[min(index, count - 1)],
+			};
+		}
+
+		if self.form as usize >= evolved.forms.len()
+		{
+			self.form = 0;
+		}
+
+		self.species = species;
+		self.stats_update();
+	}
+
+	// The first species evolved into with a trigger for which `check` returns true.
+	fn evolution_find<F>(&self, check: F) -> Option<SpeciesType>
+		where F: Fn(EvolutionTrigger) -> bool
+	{
+		self.species.species().evolutions.iter().find(|evolution|
+		{
+			check(evolution.trigger)
+		})
+		.map(|evolution| evolution.species)
+	}
+
+	// Recalculates statistics, keeping health that was missing before recalculating missing.
+	fn stats_update(&mut self)
+	{
+		let health_missing = self.stat_health - self.health;
+		self.stats_recalculate();
+		if self.health != 0
+		{
+			self.health = self.stat_health.saturating_sub(health_missing);
+		}
+	}
+
+	// Fills the attacks with the most recent ones learnable at the current level and form.
+	fn attacks_fill(&mut self)
+	{
+		let species = self.species.species();
+
+		// The index at which the level is closest to.
+		let mut attack_level_index = species.attacks_learnable.binary_search_by(|&(level, _)|
+		{
+			level.cmp(&self.level)
+		})
+		.unwrap_or_else(|index| index - 1);
+
+		// The index at which the monster's attack list is filled.
+		let mut attack_filled_index = 0;
+		'outer: loop
+		{
+			let (_, attacks_forms) = species.attacks_learnable[attack_level_index];
+			let attack_list = attacks_forms[self.form as usize];
+			for attack in attack_list
+			{
+				self.attacks.insert(0, MonsterAttack::new(species.attacks_list[*attack]));
+				attack_filled_index += 1;
+				if attack_filled_index == ATTACK_LIMIT
+				{
+					break 'outer;
+				}
+			}
+
+			if attack_level_index == 0
+			{
+				break;
+			}
+
+			attack_level_index -= 1;
+		}
+	}
+
+	// The attacks that the species and form can learn upon reaching exactly the given level.
+	fn attacks_learnable_at(&self, level: LevelType) -> Vec<AttackType>
+	{
+		let species = self.species.species();
+		match species.attacks_learnable.binary_search_by(|&(learn_level, _)|
+		{
+			learn_level.cmp(&level)
+		})
+		{
+			Ok(index) =>
+			{
+				let (_, attacks_forms) = species.attacks_learnable[index];
+				attacks_forms[self.form as usize].iter().map(|attack|
+				{
+					species.attacks_list[*attack]
+				})
+				.collect()
+			}
+			Err(_) => Vec::new(),
+		}
+	}
+
+	// The default nick for monsters of the given species.
+	fn species_nick(species: SpeciesType) -> CString
+	{
+		unsafe
+		{
+			CStr::from_ptr(species.species().name.as_ptr() as *const c_char)
+		}
+		.to_owned()
 	}
 
 	pub fn stat_health(&self) -> StatType
@@ -388,6 +672,44 @@ impl Monster
 		self.ev_speed
 	}
 
+	/// Adds effort values, ordered like `MonsterLevelUp::stats`, and recalculates statistics.
+	///
+	/// Effort values stop increasing at `EFFORT_LIMIT` per statistic and at `EFFORT_TOTAL_LIMIT`
+	/// for all statistics together. Returns the effort values that were actually added.
+	///
+	pub fn efforts_add(&mut self, efforts: &[StatYieldType; STAT_COUNT])
+		-> [StatYieldType; STAT_COUNT]
+	{
+		let added = self.efforts_capped(efforts);
+		{
+			let mut values = [&mut self.ev_health, &mut self.ev_attack, &mut self.ev_defense,
+				&mut self.ev_spattack, &mut self.ev_spdefense, &mut self.ev_speed];
+			for (value, amount) in values.iter_mut().zip(&added)
+			{
+				**value += *amount;
+			}
+		}
+		self.stats_update();
+		added
+	}
+
+	/// The effort values that `efforts_add` would add for the given effort values.
+	pub fn efforts_capped(&self, efforts: &[StatYieldType; STAT_COUNT])
+		-> [StatYieldType; STAT_COUNT]
+	{
+		let current = self.efforts();
+		let mut total = current.iter().fold(0, |total, value| total + *value as u16);
+		let mut added = [0; STAT_COUNT];
+		for index in 0..STAT_COUNT
+		{
+			let room = min(EFFORT_LIMIT.saturating_sub(current[index]) as u16,
+				EFFORT_TOTAL_LIMIT.saturating_sub(total));
+			added[index] = min(efforts[index] as u16, room) as StatYieldType;
+			total += added[index] as u16;
+		}
+		added
+	}
+
 	pub fn individual_health(&self) -> StatIndividualType
 	{
 		self.iv_health
@@ -478,11 +800,48 @@ impl Monster
 		}
 	}
 
+	/// Learns the given attack into a free slot or by replacing the attack at index `replace`.
+	///
+	/// Returns false without learning if the attack is already known, cannot be learned by the
+	/// species, there is no free slot or `replace` is not a known attack index.
+	///
+	pub fn attack_learn(&mut self, attack: AttackType, replace: Option<usize>) -> bool
+	{
+		if self.attacks.iter().any(|known| known.attack_type == attack)
+		{
+			return false;
+		}
+		match replace
+		{
+			Some(index) if index < self.attacks.len() => self.attack_set(attack, index),
+			Some(_) => false,
+			None => self.attack_add(attack),
+		}
+	}
+
 	pub fn attack_remove(&mut self, index: usize)
 	{
 		self.attacks.remove(index);
 	}
 
+	/// The item held in battle, if any.
+	pub fn held_item(&self) -> Option<ItemType>
+	{
+		self.held_item
+	}
+
+	/// Gives an item to hold, replacing any item that is already held.
+	pub fn held_item_set(&mut self, item: ItemType)
+	{
+		self.held_item = Some(item);
+	}
+
+	/// Removes the held item, returning it if there was any.
+	pub fn held_item_take(&mut self) -> Option<ItemType>
+	{
+		self.held_item.take()
+	}
+
 	pub fn recruited(&self) -> bool
 	{
 		self.recruited.is_some()
@@ -493,11 +852,1101 @@ impl Monster
 		self.recruited.unwrap()
 	}
 
-	// pub fn recruit_location()
+	/// The location the monster was recruited at, if it is known.
+	pub fn recruit_location(&self) -> Option<Location>
+	{
+		self.recruit_location
+	}
+
+	/// Marks the monster as recruited using `method` at `location` at its current level.
+	pub fn recruit_set(&mut self, method: RecruitMethod, location: Option<Location>)
+	{
+		self.recruited = Some(method);
+		self.recruit_location = location;
+		self.recruit_level = self.level;
+	}
 
-	// pub fn recruit_set(method, location);
+	/// The more specific place within the recruit location, such as grass or water, if known.
+	pub fn recruit_sub_location(&self) -> Option<SubLocation>
+	{
+		self.recruit_sub_location
+	}
 
-// 	// fn trainer() -> TrainerType;
-//
-// 	// fn ailment() -> AilmentType;
+	pub fn recruit_sub_location_set(&mut self, sub_location: Option<SubLocation>)
+	{
+		self.recruit_sub_location = sub_location;
+	}
+
+	/// The level the monster was recruited at. Only meaningful if the monster was recruited.
+	pub fn recruit_level(&self) -> LevelType
+	{
+		self.recruit_level
+	}
+
+	/// The date the monster was recruited on, if it is known.
+	pub fn recruit_date(&self) -> Option<DateType>
+	{
+		self.recruit_date
+	}
+
+	pub fn recruit_date_set(&mut self, date: Option<DateType>)
+	{
+		self.recruit_date = date;
+	}
+
+	/// The trainer that first recruited the monster, if any.
+	pub fn trainer(&self) -> Option<&Trainer>
+	{
+		self.trainer.as_ref()
+	}
+
+	/// Replaces the original trainer. Trading a monster does not change its original trainer.
+	pub fn trainer_set(&mut self, trainer: Trainer)
+	{
+		self.trainer = Some(trainer);
+	}
+
+	/// Whether the monster belongs to someone other than its original trainer.
+	///
+	/// Monsters without an original trainer, such as wild monsters, are never traded.
+	///
+	pub fn traded(&self, owner: Option<&Trainer>) -> bool
+	{
+		match (self.trainer.as_ref(), owner)
+		{
+			(Some(trainer), Some(owner)) => trainer != owner,
+			_ => false,
+		}
+	}
+
+	/// The non-volatile ailment that persists even after battle, if any.
+	pub fn ailment(&self) -> Option<Ailment>
+	{
+		self.ailment
+	}
+
+	/// The number of turns left before the current ailment wears off.
+	///
+	/// Only ailments that wear off on their own, such as sleep, use this value.
+	///
+	pub fn ailment_turns(&self) -> u8
+	{
+		self.ailment_turns
+	}
+
+	/// Replaces the current ailment. Ailments that do not wear off on their own ignore `turns`.
+	pub fn ailment_set(&mut self, ailment: Ailment, turns: u8)
+	{
+		self.ailment = Some(ailment);
+		self.ailment_turns = turns;
+	}
+
+	/// Lowers the number of turns left for the current ailment by one.
+	pub fn ailment_turns_take(&mut self)
+	{
+		self.ailment_turns = self.ailment_turns.saturating_sub(1);
+	}
+
+	/// Removes the current ailment.
+	pub fn ailment_cure(&mut self)
+	{
+		self.ailment = None;
+		self.ailment_turns = 0;
+	}
+}
+
+/// The highest individual value of a single statistic.
+pub const INDIVIDUAL_MAX: StatIndividualType = 31;
+
+/// Represents an error from creating a monster with `MonsterBuilder`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MonsterBuildError
+{
+	/// The level is not between 1 and 100.
+	InvalidLevel(LevelType),
+	/// The species does not have the form.
+	InvalidForm(FormId),
+	/// The nick contains a null character.
+	InvalidNick,
+	/// The gender is not possible with the gender ratio of the species.
+	InvalidGender(Gender),
+	/// The species is never given the ability.
+	InvalidAbility(AbilityType),
+	/// An individual value is greater than `INDIVIDUAL_MAX`.
+	InvalidIndividual,
+	/// The effort values are greater than `EFFORT_LIMIT` or `EFFORT_TOTAL_LIMIT`.
+	InvalidEffort,
+	/// There are more than `ATTACK_LIMIT` attacks or an attack is repeated.
+	InvalidAttackCount,
+	/// The species cannot learn the attack.
+	InvalidAttack(AttackType),
+}
+
+impl fmt::Display for MonsterBuildError
+{
+	fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error>
+	{
+		match *self
+		{
+			MonsterBuildError::InvalidLevel(level) => write!(f, "Invalid level {}", level),
+			MonsterBuildError::InvalidForm(form) => write!(f, "Invalid form {}", form),
+			MonsterBuildError::InvalidNick => write!(f, "Nick contains a null character"),
+			MonsterBuildError::InvalidGender(gender) =>
+			{
+				write!(f, "Gender {:?} is not possible for the species", gender)
+			}
+			MonsterBuildError::InvalidAbility(ability) =>
+			{
+				write!(f, "Ability {:?} is not given to the species", ability)
+			}
+			MonsterBuildError::InvalidIndividual => write!(f, "Individual value is too high"),
+			MonsterBuildError::InvalidEffort => write!(f, "Effort values are too high"),
+			MonsterBuildError::InvalidAttackCount =>
+			{
+				write!(f, "Too many or repeated attacks")
+			}
+			MonsterBuildError::InvalidAttack(attack) =>
+			{
+				write!(f, "Attack {:?} cannot be learned by the species", attack)
+			}
+		}
+	}
+}
+
+impl error::Error for MonsterBuildError
+{
+	fn description(&self) -> &str
+	{
+		match *self
+		{
+			MonsterBuildError::InvalidLevel(_) => "invalid level",
+			MonsterBuildError::InvalidForm(_) => "invalid form",
+			MonsterBuildError::InvalidNick => "invalid nick",
+			MonsterBuildError::InvalidGender(_) => "invalid gender",
+			MonsterBuildError::InvalidAbility(_) => "invalid ability",
+			MonsterBuildError::InvalidIndividual => "invalid individual value",
+			MonsterBuildError::InvalidEffort => "invalid effort values",
+			MonsterBuildError::InvalidAttackCount => "invalid number of attacks",
+			MonsterBuildError::InvalidAttack(_) => "invalid attack",
+		}
+	}
+}
+
+/// Creates a monster with chosen values.
+///
+/// Values that are not chosen are random, like with `Monster::new`. The randomness can be seeded so
+/// that the same monster is created every time. Attacks that are not chosen are the most recent
+/// ones learnable at the level.
+///
+#[derive(Debug, Clone)]
+pub struct MonsterBuilder
+{
+	species: SpeciesType,
+	level: LevelType,
+	form: FormId,
+	nick: Option<String>,
+	personality: Option<PersonalityType>,
+	gender: Option<Gender>,
+	nature: Option<Nature>,
+	ability: Option<AbilityType>,
+	individuals: Option<[StatIndividualType; STAT_COUNT]>,
+	efforts: [StatYieldType; STAT_COUNT],
+	attacks: Vec<AttackType>,
+	held_item: Option<ItemType>,
+	seed: Option<usize>,
+}
+
+impl MonsterBuilder
+{
+	pub fn new(species: SpeciesType, level: LevelType) -> Self
+	{
+		MonsterBuilder
+		{
+			species: species,
+			level: level,
+			form: 0,
+			nick: None,
+			personality: None,
+			gender: None,
+			nature: None,
+			ability: None,
+			individuals: None,
+			efforts: [0; STAT_COUNT],
+			attacks: Vec::new(),
+			held_item: None,
+			seed: None,
+		}
+	}
+
+	pub fn form(mut self, form: FormId) -> Self
+	{
+		self.form = form;
+		self
+	}
+
+	pub fn nick(mut self, nick: &str) -> Self
+	{
+		self.nick = Some(nick.to_string());
+		self
+	}
+
+	/// Chooses the personality. The gender and ability are derived from it unless chosen.
+	pub fn personality(mut self, personality: PersonalityType) -> Self
+	{
+		self.personality = Some(personality);
+		self
+	}
+
+	pub fn gender(mut self, gender: Gender) -> Self
+	{
+		self.gender = Some(gender);
+		self
+	}
+
+	pub fn nature(mut self, nature: Nature) -> Self
+	{
+		self.nature = Some(nature);
+		self
+	}
+
+	pub fn ability(mut self, ability: AbilityType) -> Self
+	{
+		self.ability = Some(ability);
+		self
+	}
+
+	/// Chooses the individual values, ordered like `MonsterLevelUp::stats`.
+	pub fn individuals(mut self, individuals: [StatIndividualType; STAT_COUNT]) -> Self
+	{
+		self.individuals = Some(individuals);
+		self
+	}
+
+	/// Chooses the effort values, ordered like `MonsterLevelUp::stats`. They are 0 otherwise.
+	pub fn efforts(mut self, efforts: [StatYieldType; STAT_COUNT]) -> Self
+	{
+		self.efforts = efforts;
+		self
+	}
+
+	/// Chooses the attacks in order, replacing any that were chosen before.
+	pub fn attacks(mut self, attacks: &[AttackType]) -> Self
+	{
+		self.attacks = attacks.to_vec();
+		self
+	}
+
+	pub fn held_item(mut self, item: ItemType) -> Self
+	{
+		self.held_item = Some(item);
+		self
+	}
+
+	/// Seeds the randomness for values that are not chosen.
+	pub fn seed(mut self, seed: usize) -> Self
+	{
+		self.seed = Some(seed);
+		self
+	}
+
+	/// Creates the monster, failing if any of the chosen values are invalid for the species.
+	///
+	/// Values that are not chosen are drawn from a generator seeded with `seed`, or from the thread
+	/// generator if there is no seed.
+	///
+	pub fn build(&self) -> Result<Monster, MonsterBuildError>
+	{
+		match self.seed
+		{
+			Some(seed) => self.build_with_rng(&mut StdRng::from_seed(&[seed])),
+			None => self.build_with_rng(&mut thread_rng()),
+		}
+	}
+
+	/// Creates the monster like `build` with values drawn from the given random number generator.
+	///
+	/// The seed is ignored. This allows deriving many monsters, like wild encounters or trainer
+	/// parties, from one seeded generator.
+	///
+	pub fn build_with_rng<R: Rng>(&self, rng: &mut R) -> Result<Monster, MonsterBuildError>
+	{
+		try!(self.validate());
+
+		let species = self.species.species();
+		let nick = match self.nick
+		{
+			Some(ref nick) => CString::new(nick.as_str()).unwrap(),
+			None => Monster::species_nick(self.species),
+		};
+
+		let iv_stat = Range::new(0, INDIVIDUAL_MAX + 1);
+		let individuals = match self.individuals
+		{
+			Some(individuals) => individuals,
+			None =>
+			{
+				let mut individuals = [0; STAT_COUNT];
+				for value in &mut individuals
+				{
+					*value = iv_stat.ind_sample(rng);
+				}
+				individuals
+			}
+		};
+
+		let personality = self.personality.unwrap_or_else(|| rng.gen());
+		let ability = match self.ability
+		{
+			Some(ability) => ability,
+			None => species.abilities[personality::ability_slot(personality,
+				species.abilities.len())],
+		};
+		let gender = match self.gender
+		{
+			Some(gender) => gender,
+			None => Gender::with_personality(personality, species.gender),
+		};
+		let nature = self.nature.unwrap_or_else(|| rng.gen());
+
+		let mut monster = Monster
+		{
+			species: self.species,
+			nick: nick,
+			form: self.form,
+			level: self.level,
+			personality: personality,
+			gender: gender,
+			nature: nature,
+			ability: ability,
+			experience: species.growth.experience_with_level(self.level),
+			friendship: species.friendship,
+			health: 0,
+			stat_health: 0,
+			stat_attack: 0,
+			stat_defense: 0,
+			stat_spattack: 0,
+			stat_spdefense: 0,
+			stat_speed: 0,
+			ev_health: self.efforts[0],
+			ev_attack: self.efforts[1],
+			ev_defense: self.efforts[2],
+			ev_spattack: self.efforts[3],
+			ev_spdefense: self.efforts[4],
+			ev_speed: self.efforts[5],
+			iv_health: individuals[0],
+			iv_attack: individuals[1],
+			iv_defense: individuals[2],
+			iv_spattack: individuals[3],
+			iv_spdefense: individuals[4],
+			iv_speed: individuals[5],
+			attacks: self.attacks.iter().map(|attack| MonsterAttack::new(*attack)).collect(),
+			held_item: self.held_item,
+			recruited: None,
+			ailment: None,
+			ailment_turns: 0,
+			recruit_location: None,
+			recruit_sub_location: None,
+			recruit_level: 0,
+			recruit_date: None,
+			trainer: None,
+		};
+
+		if monster.attacks.is_empty()
+		{
+			monster.attacks_fill();
+		}
+
+		monster.stats_recalculate();
+		monster.health_restore();
+		Ok(monster)
+	}
+
+	// Checks that every chosen value is valid for the species.
+	fn validate(&self) -> Result<(), MonsterBuildError>
+	{
+		let species = self.species.species();
+		try!(values_validate(self.species, self.level, self.form,
+			&self.individuals.unwrap_or([0; STAT_COUNT]), &self.efforts, &self.attacks));
+		if let Some(ref nick) = self.nick
+		{
+			if nick.contains('\0')
+			{
+				return Err(MonsterBuildError::InvalidNick);
+			}
+		}
+		if let Some(gender) = self.gender
+		{
+			if !gender.possible(species.gender)
+			{
+				return Err(MonsterBuildError::InvalidGender(gender));
+			}
+		}
+		if let Some(ability) = self.ability
+		{
+			if !species.abilities.contains(&ability) && !species.abilities_hidden.contains(&ability)
+			{
+				return Err(MonsterBuildError::InvalidAbility(ability));
+			}
+		}
+		for attack in &self.attacks
+		{
+			if !species.attack_valid(*attack)
+			{
+				return Err(MonsterBuildError::InvalidAttack(*attack));
+			}
+		}
+		Ok(())
+	}
+}
+
+// Checks the values that every monster must have in range, whether built or loaded.
+//
+// Whether the species is normally given the gender, ability or attacks is not checked, since
+// monsters can be changed to have them after they are created, such as by evolving.
+//
+fn values_validate(species: SpeciesType, level: LevelType, form: FormId,
+	individuals: &[StatIndividualType; STAT_COUNT], efforts: &[StatYieldType; STAT_COUNT],
+	attacks: &[AttackType]) -> Result<(), MonsterBuildError>
+{
+	if level == 0 || level > 100
+	{
+		return Err(MonsterBuildError::InvalidLevel(level));
+	}
+	if form as usize >= species.species().forms.len()
+	{
+		return Err(MonsterBuildError::InvalidForm(form));
+	}
+	if individuals.iter().any(|value| *value > INDIVIDUAL_MAX)
+	{
+		return Err(MonsterBuildError::InvalidIndividual);
+	}
+	let effort_total = efforts.iter().fold(0, |total, value| total + *value as u16);
+	if efforts.iter().any(|value| *value > EFFORT_LIMIT) || effort_total > EFFORT_TOTAL_LIMIT
+	{
+		return Err(MonsterBuildError::InvalidEffort);
+	}
+	if attacks.len() > ATTACK_LIMIT
+	{
+		return Err(MonsterBuildError::InvalidAttackCount);
+	}
+	for (index, attack) in attacks.iter().enumerate()
+	{
+		if attacks[..index].contains(attack)
+		{
+			return Err(MonsterBuildError::InvalidAttackCount);
+		}
+	}
+	Ok(())
+}
+
+/// The current version of the saved monster list format.
+///
+/// Monster lists saved with a different version cannot be loaded. The version only changes once a
+/// released format changes, not while the format is extended before release.
+///
+pub const MONSTER_VERSION: u32 = 1;
+
+/// The first bytes of a binary monster list.
+const MONSTER_MAGIC: &'static [u8; 4] = b"MONM";
+
+impl Monster
+{
+	/// Saves every value of this monster as a TOML table.
+	///
+	/// Statistics are not stored since they are recalculated when loading.
+	///
+	pub fn to_toml(&self) -> toml::Table
+	{
+		let mut table = toml::Table::new();
+		table.insert("species".to_string(), toml_int(self.species as SpeciesId));
+		table.insert("nick".to_string(), toml::Value::String(self.nick().to_string()));
+		table.insert("form".to_string(), toml_int(self.form));
+		table.insert("level".to_string(), toml_int(self.level));
+		table.insert("personality".to_string(), toml_int(self.personality));
+		table.insert("gender".to_string(), toml_int(self.gender as GenderId));
+		table.insert("nature".to_string(), toml_int(self.nature as NatureId));
+		table.insert("ability".to_string(), toml_int(self.ability as AbilityId));
+		table.insert("experience".to_string(), toml_int(self.experience));
+		table.insert("friendship".to_string(), toml_int(self.friendship));
+		table.insert("health".to_string(), toml_int(self.health));
+		table.insert("effort".to_string(), toml::Value::Array(self.efforts().iter().map(|value|
+		{
+			toml_int(*value)
+		}).collect()));
+		table.insert("individual".to_string(), toml::Value::Array(self.individuals().iter().map(
+			|value|
+		{
+			toml_int(*value)
+		}).collect()));
+		table.insert("attack".to_string(), toml::Value::Array(self.attacks.iter().map(|attack|
+		{
+			let mut attack_table = toml::Table::new();
+			attack_table.insert("attack".to_string(), toml_int(attack.attack_type as AttackId));
+			attack_table.insert("limit_left".to_string(), toml_int(attack.limit_left));
+			attack_table.insert("limit_upgraded".to_string(), toml_int(attack.limit_upgraded));
+			toml::Value::Table(attack_table)
+		}).collect()));
+		if let Some(held_item) = self.held_item
+		{
+			table.insert("held_item".to_string(), toml_int(held_item as ItemId));
+		}
+		if let Some(recruited) = self.recruited
+		{
+			table.insert("recruited".to_string(), toml_int(recruited as RecruitMethodId));
+		}
+		if let Some(location) = self.recruit_location
+		{
+			table.insert("recruit_location".to_string(), toml_int(location as LocationId));
+		}
+		if let Some(sub_location) = self.recruit_sub_location
+		{
+			table.insert("recruit_sub_location".to_string(), toml_int(sub_location as
+				SubLocationId));
+		}
+		table.insert("recruit_level".to_string(), toml_int(self.recruit_level));
+		if let Some(date) = self.recruit_date
+		{
+			table.insert("recruit_date".to_string(), toml_int(date));
+		}
+		if let Some(ref trainer) = self.trainer
+		{
+			table.insert("trainer".to_string(), toml::Value::Table(trainer.to_toml()));
+		}
+		if let Some(ailment) = self.ailment
+		{
+			table.insert("ailment".to_string(), toml_int(ailment as AilmentId));
+			table.insert("ailment_turns".to_string(), toml_int(self.ailment_turns));
+		}
+		table
+	}
+
+	/// Loads a monster saved with `to_toml`.
+	pub fn from_toml(table: &toml::Table) -> SerializeResult<Self>
+	{
+		let efforts = try!(Monster::stats_from_toml(table, "effort"));
+		let individuals = try!(Monster::stats_from_toml(table, "individual"));
+
+		let mut attacks = Vec::new();
+		for attack in try!(toml_get_array(table, "attack"))
+		{
+			let attack_table = try!(toml_as_table(attack, "attack"));
+			attacks.push(MonsterAttack
+			{
+				attack_type: try!(attack_type_from_id(try!(toml_get(attack_table, "attack")))),
+				limit_left: try!(toml_get(attack_table, "limit_left")),
+				limit_upgraded: try!(toml_get(attack_table, "limit_upgraded")),
+			});
+		}
+
+		let held_item = match table.get("held_item")
+		{
+			Some(_) => Some(try!(item_type_from_id(try!(toml_get(table, "held_item"))))),
+			None => None,
+		};
+
+		let recruited = match table.get("recruited")
+		{
+			Some(_) => Some(try!(recruit_method_from_id(try!(toml_get(table, "recruited"))))),
+			None => None,
+		};
+
+		let recruit_location = match table.get("recruit_location")
+		{
+			Some(_) => Some(try!(location_from_id(try!(toml_get(table, "recruit_location"))))),
+			None => None,
+		};
+
+		let recruit_sub_location = match table.get("recruit_sub_location")
+		{
+			Some(_) => Some(try!(sub_location_from_id(try!(toml_get(table,
+				"recruit_sub_location"))))),
+			None => None,
+		};
+
+		let recruit_date = match table.get("recruit_date")
+		{
+			Some(_) => Some(try!(toml_get(table, "recruit_date"))),
+			None => None,
+		};
+
+		let trainer = match table.get("trainer")
+		{
+			Some(trainer) => Some(try!(Trainer::from_toml(try!(toml_as_table(trainer,
+				"trainer"))))),
+			None => None,
+		};
+
+		let ailment = match table.get("ailment")
+		{
+			Some(_) => Some(try!(ailment_from_id(try!(toml_get(table, "ailment"))))),
+			None => None,
+		};
+
+		let mut monster = try!(Monster::from_values(try!(toml_get(table, "species")),
+			try!(toml_get(table, "gender")), try!(toml_get(table, "nature")), &efforts,
+			&individuals, attacks));
+		monster.nick = try!(CString::new(try!(toml_get_str(table, "nick"))).or_else(|_|
+			invalid("nick")));
+		monster.form = try!(toml_get(table, "form"));
+		monster.level = try!(toml_get(table, "level"));
+		monster.personality = try!(toml_get(table, "personality"));
+		monster.ability = try!(ability_type_from_id(try!(toml_get(table, "ability"))));
+		monster.experience = try!(toml_get(table, "experience"));
+		monster.friendship = try!(toml_get(table, "friendship"));
+		monster.health = try!(toml_get(table, "health"));
+		monster.held_item = held_item;
+		monster.recruited = recruited;
+		monster.recruit_location = recruit_location;
+		monster.recruit_sub_location = recruit_sub_location;
+		monster.recruit_level = try!(toml_get(table, "recruit_level"));
+		monster.recruit_date = recruit_date;
+		monster.trainer = trainer;
+		if let Some(ailment) = ailment
+		{
+			monster.ailment_set(ailment, try!(toml_get(table, "ailment_turns")));
+		}
+		try!(monster.validate());
+		Ok(monster)
+	}
+
+	/// Saves the same values as `to_toml` in a compact binary format.
+	pub fn write_binary(&self, out: &mut Write) -> io::Result<()>
+	{
+		try!(write_u16(out, self.species as SpeciesId));
+		try!(write_bytes_u16(out, self.nick.as_bytes()));
+		try!(write_u8(out, self.form));
+		try!(write_u8(out, self.level));
+		try!(write_u32(out, self.personality));
+		try!(write_u8(out, self.gender as GenderId));
+		try!(write_u8(out, self.nature as NatureId));
+		try!(write_u8(out, self.ability as AbilityId));
+		try!(write_u32(out, self.experience));
+		try!(write_u8(out, self.friendship));
+		try!(write_u16(out, self.health));
+		for value in self.efforts().iter().chain(self.individuals().iter())
+		{
+			try!(write_u8(out, *value));
+		}
+		try!(write_u8(out, self.attacks.len() as u8));
+		for attack in &self.attacks
+		{
+			try!(write_u16(out, attack.attack_type as AttackId));
+			try!(write_u8(out, attack.limit_left));
+			try!(write_u8(out, attack.limit_upgraded));
+		}
+		match self.held_item
+		{
+			Some(held_item) =>
+			{
+				try!(write_u8(out, 1));
+				try!(write_u16(out, held_item as ItemId));
+			}
+			None => try!(write_u8(out, 0)),
+		}
+		match self.recruited
+		{
+			Some(recruited) =>
+			{
+				try!(write_u8(out, 1));
+				try!(write_u8(out, recruited as RecruitMethodId));
+			}
+			None => try!(write_u8(out, 0)),
+		}
+		match self.recruit_location
+		{
+			Some(location) =>
+			{
+				try!(write_u8(out, 1));
+				try!(write_u8(out, location as LocationId));
+			}
+			None => try!(write_u8(out, 0)),
+		}
+		match self.recruit_sub_location
+		{
+			Some(sub_location) =>
+			{
+				try!(write_u8(out, 1));
+				try!(write_u8(out, sub_location as SubLocationId));
+			}
+			None => try!(write_u8(out, 0)),
+		}
+		try!(write_u8(out, self.recruit_level));
+		match self.recruit_date
+		{
+			Some(date) =>
+			{
+				try!(write_u8(out, 1));
+				try!(write_u32(out, date));
+			}
+			None => try!(write_u8(out, 0)),
+		}
+		match self.trainer
+		{
+			Some(ref trainer) =>
+			{
+				try!(write_u8(out, 1));
+				try!(trainer.write_binary(out));
+			}
+			None => try!(write_u8(out, 0)),
+		}
+		match self.ailment
+		{
+			Some(ailment) =>
+			{
+				try!(write_u8(out, 1));
+				try!(write_u8(out, ailment as AilmentId));
+				write_u8(out, self.ailment_turns)
+			}
+			None => write_u8(out, 0),
+		}
+	}
+
+	/// Loads a monster saved with `write_binary`.
+	pub fn read_binary(input: &mut Read) -> SerializeResult<Self>
+	{
+		let species = try!(read_u16(input));
+		let mut nick = vec![0u8; try!(read_u16(input)) as usize];
+		try!(input.read_exact(&mut nick));
+		let form = try!(read_u8(input));
+		let level = try!(read_u8(input));
+		let personality = try!(read_u32(input));
+		let gender = try!(read_u8(input));
+		let nature = try!(read_u8(input));
+		let ability = try!(ability_type_from_id(try!(read_u8(input))));
+		let experience = try!(read_u32(input));
+		let friendship = try!(read_u8(input));
+		let health = try!(read_u16(input));
+
+		let mut efforts = [0; STAT_COUNT];
+		let mut individuals = [0; STAT_COUNT];
+		for value in efforts.iter_mut().chain(individuals.iter_mut())
+		{
+			*value = try!(read_u8(input));
+		}
+
+		let attack_count = try!(read_u8(input));
+		let mut attacks = Vec::with_capacity(attack_count as usize);
+		for _ in 0..attack_count
+		{
+			attacks.push(MonsterAttack
+			{
+				attack_type: try!(attack_type_from_id(try!(read_u16(input)))),
+				limit_left: try!(read_u8(input)),
+				limit_upgraded: try!(read_u8(input)),
+			});
+		}
+
+		let held_item = if try!(read_u8(input)) != 0
+		{
+			Some(try!(item_type_from_id(try!(read_u16(input)))))
+		}
+		else
+		{
+			None
+		};
+
+		let recruited = if try!(read_u8(input)) != 0
+		{
+			Some(try!(recruit_method_from_id(try!(read_u8(input)))))
+		}
+		else
+		{
+			None
+		};
+
+		let recruit_location = if try!(read_u8(input)) != 0
+		{
+			Some(try!(location_from_id(try!(read_u8(input)))))
+		}
+		else
+		{
+			None
+		};
+
+		let recruit_sub_location = if try!(read_u8(input)) != 0
+		{
+			Some(try!(sub_location_from_id(try!(read_u8(input)))))
+		}
+		else
+		{
+			None
+		};
+
+		let recruit_level = try!(read_u8(input));
+		let recruit_date = if try!(read_u8(input)) != 0
+		{
+			Some(try!(read_u32(input)))
+		}
+		else
+		{
+			None
+		};
+
+		let trainer = if try!(read_u8(input)) != 0
+		{
+			Some(try!(Trainer::read_binary(input)))
+		}
+		else
+		{
+			None
+		};
+
+		let ailment = if try!(read_u8(input)) != 0
+		{
+			Some((try!(ailment_from_id(try!(read_u8(input)))), try!(read_u8(input))))
+		}
+		else
+		{
+			None
+		};
+
+		let mut monster = try!(Monster::from_values(species, gender, nature, &efforts, &individuals,
+			attacks));
+		monster.nick = try!(String::from_utf8(nick).ok().and_then(|nick| CString::new(nick).ok())
+			.map_or_else(|| invalid("nick"), Ok));
+		monster.form = form;
+		monster.level = level;
+		monster.personality = personality;
+		monster.ability = ability;
+		monster.experience = experience;
+		monster.friendship = friendship;
+		monster.health = health;
+		monster.held_item = held_item;
+		monster.recruited = recruited;
+		monster.recruit_location = recruit_location;
+		monster.recruit_sub_location = recruit_sub_location;
+		monster.recruit_level = recruit_level;
+		monster.recruit_date = recruit_date;
+		monster.trainer = trainer;
+		if let Some((ailment, turns)) = ailment
+		{
+			monster.ailment_set(ailment, turns);
+		}
+		try!(monster.validate());
+		Ok(monster)
+	}
+
+	/// Saves a list of monsters, such as a party or storage box, as TOML.
+	///
+	/// Unlike replays, lists are not tied to the generated species and attack tables. As long as
+	/// identifiers are not removed or reordered, lists remain loadable after new resources are
+	/// added.
+	///
+	pub fn save_list_toml(monsters: &[Monster], out: &mut Write) -> io::Result<()>
+	{
+		let mut table = toml::Table::new();
+		table.insert("version".to_string(), toml_int(MONSTER_VERSION));
+		table.insert("monster".to_string(), toml::Value::Array(monsters.iter().map(|monster|
+		{
+			toml::Value::Table(monster.to_toml())
+		}).collect()));
+		write!(out, "{}", toml::Value::Table(table))
+	}
+
+	/// Loads a list of monsters saved with `save_list_toml`.
+	///
+	/// Fails with `SerializeError::InvalidSpecies` or `SerializeError::InvalidAttack` if the list
+	/// refers to resources that no longer exist.
+	///
+	pub fn load_list_toml(input: &mut Read) -> SerializeResult<Vec<Monster>>
+	{
+		let table = try!(toml_read(input));
+
+		let version = try!(toml_get(&table, "version"));
+		if version != MONSTER_VERSION
+		{
+			return Err(SerializeError::VersionMismatch(version));
+		}
+
+		let mut monsters = Vec::new();
+		for monster in try!(toml_get_array(&table, "monster"))
+		{
+			monsters.push(try!(Monster::from_toml(try!(toml_as_table(monster, "monster")))));
+		}
+		Ok(monsters)
+	}
+
+	/// Saves a list of monsters in a compact binary format.
+	///
+	/// See `save_list_toml` for details.
+	///
+	pub fn save_list_binary(monsters: &[Monster], out: &mut Write) -> io::Result<()>
+	{
+		try!(out.write_all(MONSTER_MAGIC));
+		try!(write_u32(out, MONSTER_VERSION));
+		try!(write_u32(out, monsters.len() as u32));
+		for monster in monsters
+		{
+			try!(monster.write_binary(out));
+		}
+		Ok(())
+	}
+
+	/// Loads a list of monsters saved with `save_list_binary`.
+	///
+	/// See `load_list_toml` for when loading fails.
+	///
+	pub fn load_list_binary(input: &mut Read) -> SerializeResult<Vec<Monster>>
+	{
+		let mut magic = [0u8; 4];
+		try!(input.read_exact(&mut magic));
+		if magic != *MONSTER_MAGIC
+		{
+			return invalid("magic");
+		}
+
+		let version = try!(read_u32(input));
+		if version != MONSTER_VERSION
+		{
+			return Err(SerializeError::VersionMismatch(version));
+		}
+
+		let count = try!(read_u32(input));
+		let mut monsters = Vec::new();
+		for _ in 0..count
+		{
+			monsters.push(try!(Monster::read_binary(input)));
+		}
+		Ok(monsters)
+	}
+
+	fn efforts(&self) -> [StatYieldType; STAT_COUNT]
+	{
+		[self.ev_health, self.ev_attack, self.ev_defense, self.ev_spattack, self.ev_spdefense,
+			self.ev_speed]
+	}
+
+	fn individuals(&self) -> [StatIndividualType; STAT_COUNT]
+	{
+		[self.iv_health, self.iv_attack, self.iv_defense, self.iv_spattack, self.iv_spdefense,
+			self.iv_speed]
+	}
+
+	fn stats_from_toml(table: &toml::Table, key: &str) -> SerializeResult<[u8; STAT_COUNT]>
+	{
+		let array = try!(toml_get_array(table, key));
+		if array.len() != STAT_COUNT
+		{
+			return invalid(key);
+		}
+		let mut stats = [0; STAT_COUNT];
+		for (stat, value) in stats.iter_mut().zip(array)
+		{
+			*stat = try!(convert(try!(value.as_integer().map_or_else(|| invalid(key), Ok)), key));
+		}
+		Ok(stats)
+	}
+
+	// Creates a monster with the given identifiers. Remaining values must be set and validated.
+	fn from_values(species: SpeciesId, gender: GenderId, nature: NatureId,
+		efforts: &[StatYieldType; STAT_COUNT], individuals: &[StatIndividualType; STAT_COUNT],
+		attacks: Vec<MonsterAttack>) -> SerializeResult<Self>
+	{
+		let species = try!(SpeciesType::from_u16(species).map_or_else(||
+			Err(SerializeError::InvalidSpecies(species)), Ok));
+
+		Ok(Monster
+		{
+			species: species,
+			nick: CString::default(),
+			form: 0,
+			level: 1,
+			personality: 0,
+			gender: try!(Gender::from_u8(gender).map_or_else(|| invalid("gender"), Ok)),
+			nature: try!(Nature::from_u8(nature).map_or_else(|| invalid("nature"), Ok)),
+			ability: species.species().abilities[0],
+			experience: 0,
+			friendship: species.species().friendship,
+			health: 0,
+			stat_health: 0,
+			stat_attack: 0,
+			stat_defense: 0,
+			stat_spattack: 0,
+			stat_spdefense: 0,
+			stat_speed: 0,
+			ev_health: efforts[0],
+			ev_attack: efforts[1],
+			ev_defense: efforts[2],
+			ev_spattack: efforts[3],
+			ev_spdefense: efforts[4],
+			ev_speed: efforts[5],
+			iv_health: individuals[0],
+			iv_attack: individuals[1],
+			iv_defense: individuals[2],
+			iv_spattack: individuals[3],
+			iv_spdefense: individuals[4],
+			iv_speed: individuals[5],
+			attacks: attacks,
+			held_item: None,
+			recruited: None,
+			ailment: None,
+			ailment_turns: 0,
+			recruit_location: None,
+			recruit_sub_location: None,
+			recruit_level: 0,
+			recruit_date: None,
+			trainer: None,
+		})
+	}
+
+	// Checks that loaded values are in range and recalculates statistics.
+	//
+	// The ranges are shared with `MonsterBuilder`. Like monsters changed after being created, loaded
+	// monsters may have a gender, ability or attacks that the species is not normally given.
+	//
+	fn validate(&mut self) -> SerializeResult<()>
+	{
+		let attacks: Vec<AttackType> = self.attacks.iter().map(|attack|
+		{
+			attack.attack_type
+		})
+		.collect();
+		if let Err(error) = values_validate(self.species, self.level, self.form,
+			&self.individuals(), &self.efforts(), &attacks)
+		{
+			return invalid(build_error_key(&error));
+		}
+
+		if self.recruit_level > self.level
+		{
+			return invalid("recruit_level");
+		}
+		if self.attacks.iter().any(|attack| attack.limit_left > attack.limit_max())
+		{
+			return invalid("attack");
+		}
+
+		self.stats_recalculate();
+		if self.health > self.stat_health
+		{
+			return invalid("health");
+		}
+		Ok(())
+	}
+}
+
+// The saved value that caused the given error.
+fn build_error_key(error: &MonsterBuildError) -> &'static str
+{
+	match *error
+	{
+		MonsterBuildError::InvalidLevel(_) => "level",
+		MonsterBuildError::InvalidForm(_) => "form",
+		MonsterBuildError::InvalidNick => "nick",
+		MonsterBuildError::InvalidGender(_) => "gender",
+		MonsterBuildError::InvalidAbility(_) => "ability",
+		MonsterBuildError::InvalidIndividual => "individual",
+		MonsterBuildError::InvalidEffort => "effort",
+		MonsterBuildError::InvalidAttackCount | MonsterBuildError::InvalidAttack(_) => "attack",
+	}
+}
+
+fn ability_type_from_id(id: AbilityId) -> SerializeResult<AbilityType>
+{
+	AbilityType::from_u8(id).map_or_else(|| Err(SerializeError::InvalidAbility(id)), Ok)
+}
+
+fn ailment_from_id(id: AilmentId) -> SerializeResult<Ailment>
+{
+	Ailment::from_u8(id).map_or_else(|| invalid("ailment"), Ok)
 }
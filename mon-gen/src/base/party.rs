@@ -1,15 +1,49 @@
-use base::monster::Monster;
+use base::monster::{Monster, MonsterLevelUp, Trainer, STAT_COUNT};
 use base::statmod::StatModifiers; 
+use base::volatile::{Volatile, VolatileType};
+use gen::attack::Ailment;
+use gen::attack_list::AttackType;
+use gen::item_list::ItemType;
+use gen::locations::{Location, SubLocation};
+use gen::monster::RecruitMethod;
 use types::monster::StatType;
-use types::attack::AccuracyType;
+use types::attack::{AccuracyType, LimitType};
+use types::generic::DateType;
+use types::item::ItemCountType;
 use types::monster::ExperienceType;
+use types::species::StatYieldType;
 
 use std::slice;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::ops::{Deref, DerefMut};
 
 // The party member priority value type for `PartyMemberPriority`. 
 pub type PartyMemberPriorityType = u8;
 
+/// Who controls a party, which changes the rules of battling against it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartyKind
+{
+	/// Wild monsters. Its members can be recruited and battles against it can be escaped from.
+	Wild,
+	/// A trainer. Defeating its members gives bonus experience.
+	Trainer,
+	/// Another player, such as over a network. Defeating its members gives no experience.
+	Link,
+}
+
+/// How experience from defeating a member is distributed between members of a party.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExperienceShare
+{
+	/// Every member that battled the defeated member gains the full amount.
+	Exposed,
+	/// Members that battled the defeated member split the full amount evenly.
+	Split,
+	/// Members that battled gain the full amount and other members with health gain a fraction.
+	Share,
+}
+
 // Constants for party member priority types.
 pub struct PartyMemberPriority;
 
@@ -29,6 +63,7 @@ pub struct PartyMember<'a>
 {
 	pub member: &'a Monster,
 	pub modifiers: &'a StatModifiers,
+	pub volatile: &'a [Volatile],
 	pub priority: PartyMemberPriorityType,
 }
 
@@ -56,12 +91,28 @@ impl<'a> PartyMember<'a>
 	}
 	pub fn speed(&self) -> StatType
 	{
-		PartyMember::stat(self.member.stat_speed(), self.modifiers.speed_value())
+		let speed = PartyMember::stat(self.member.stat_speed(), self.modifiers.speed_value());
+		if self.member.ailment() == Some(Ailment::Paralysis)
+		{
+			speed / 2
+		}
+		else
+		{
+			speed
+		}
 	}
 	pub fn modifiers(&self) -> &'a StatModifiers
 	{
 		self.modifiers
 	}
+	pub fn volatile(&self) -> &'a [Volatile]
+	{
+		self.volatile
+	}
+	pub fn volatile_has(&self, volatile_type: VolatileType) -> bool
+	{
+		self.volatile.iter().any(|volatile| volatile.volatile_type() == volatile_type)
+	}
 	pub fn priority(&self) -> PartyMemberPriorityType
 	{
 		self.priority
@@ -74,12 +125,59 @@ struct PartyMemberMeta
 	member: usize,
 	modifiers: StatModifiers,
 	exposed_new: HashMap<usize, HashSet<usize>>,
+	volatile: Vec<Volatile>,
+}
+
+// Party members that are either borrowed from or owned by the party.
+#[derive(Debug)]
+enum PartyMembers<'a>
+{
+	Borrowed(&'a mut [Monster]),
+	Owned(Vec<Monster>),
+}
+
+impl<'a> PartyMembers<'a>
+{
+	fn into_vec(self) -> Vec<Monster>
+	{
+		match self
+		{
+			PartyMembers::Borrowed(members) => members.to_vec(),
+			PartyMembers::Owned(members) => members,
+		}
+	}
+}
+
+impl<'a> Deref for PartyMembers<'a>
+{
+	type Target = [Monster];
+
+	fn deref(&self) -> &[Monster]
+	{
+		match *self
+		{
+			PartyMembers::Borrowed(ref members) => members,
+			PartyMembers::Owned(ref members) => members,
+		}
+	}
+}
+
+impl<'a> DerefMut for PartyMembers<'a>
+{
+	fn deref_mut(&mut self) -> &mut [Monster]
+	{
+		match *self
+		{
+			PartyMembers::Borrowed(ref mut members) => members,
+			PartyMembers::Owned(ref mut members) => members,
+		}
+	}
 }
 
 #[derive(Debug)]
 pub struct Party<'a>
 {
-	members: &'a mut [Monster],
+	members: PartyMembers<'a>,
 	active: Vec<PartyMemberMeta>,
 	side: u8,
 	gain_experience: bool,
@@ -93,12 +191,47 @@ pub struct Party<'a>
 	// The indices of party members to map back to original order.
 	reference_order: Vec<usize>,
 
+	// The number of each item that can be used from the bag.
+	bag: BTreeMap<ItemType, ItemCountType>,
+
+	// The trainer that owns the party, if any.
+	trainer: Option<Trainer>,
+
+	// Who controls the party.
+	kind: PartyKind,
+
+	// How experience is distributed between members.
+	experience_share: ExperienceShare,
+
+	// The number of failed attempts to escape from the battle.
+	escape_attempts: u8,
+
 	// TODO: Add vec item_locked: bool,
 }
 
 impl<'a> Party<'a>
 {
+	/// Creates a party that borrows its members.
+	///
+	/// Members are modified in place, so experience, health and attack limits are applied as the
+	/// battle goes on. Switching members also reorders them.
+	///
 	pub fn new(members: &'a mut [Monster], side: u8, out: usize, gain_experience: bool) -> Self
+	{
+		Party::with_members(PartyMembers::Borrowed(members), side, out, gain_experience)
+	}
+
+	/// Creates a party that owns its members.
+	///
+	/// Owned parties can be stored or sent across threads without borrowing. Use `into_members`
+	/// to take the members back when the battle is finished.
+	///
+	pub fn with_owned(members: Vec<Monster>, side: u8, out: usize, gain_experience: bool) -> Self
+	{
+		Party::with_members(PartyMembers::Owned(members), side, out, gain_experience)
+	}
+
+	fn with_members(members: PartyMembers<'a>, side: u8, out: usize, gain_experience: bool) -> Self
 	{
 		let mut reference_order = Vec::with_capacity(members.len());
 		for i in 0..members.len() {
@@ -113,6 +246,11 @@ impl<'a> Party<'a>
 			alive: 0,
 			switch_waiting: 0,
 			reference_order: reference_order,
+			bag: BTreeMap::new(),
+			trainer: None,
+			kind: PartyKind::Wild,
+			experience_share: ExperienceShare::Exposed,
+			escape_attempts: 0,
 		};
 
 		for member_index in 0..party.members.len()
@@ -126,6 +264,7 @@ impl<'a> Party<'a>
 						member: member_index,
 						modifiers: Default::default(),
 						exposed_new: HashMap::new(),
+						volatile: Vec::new(),
 					});
 				}
 				else
@@ -148,6 +287,90 @@ impl<'a> Party<'a>
 	{
 		self.gain_experience
 	}
+
+	/// The trainer that owns the party, if any. Wild parties have no trainer.
+	pub fn trainer(&self) -> Option<&Trainer>
+	{
+		self.trainer.as_ref()
+	}
+
+	/// Sets the trainer that owns the party.
+	///
+	/// Members recruited by the party have this as their original trainer. Members with a
+	/// different original trainer are traded and gain more experience.
+	///
+	pub fn trainer_set(&mut self, trainer: Trainer)
+	{
+		self.trainer = Some(trainer);
+	}
+
+	/// Who controls the party. Parties are wild unless set otherwise.
+	pub fn kind(&self) -> PartyKind
+	{
+		self.kind
+	}
+
+	pub fn kind_set(&mut self, kind: PartyKind)
+	{
+		self.kind = kind;
+	}
+
+	/// How experience is distributed between members. Only members that battled gain it unless
+	/// set otherwise.
+	pub fn experience_share(&self) -> ExperienceShare
+	{
+		self.experience_share
+	}
+
+	pub fn experience_share_set(&mut self, experience_share: ExperienceShare)
+	{
+		self.experience_share = experience_share;
+	}
+
+	/// The number of failed attempts to escape from the battle so far.
+	pub fn escape_attempts(&self) -> u8
+	{
+		self.escape_attempts
+	}
+
+	pub fn escape_attempts_add(&mut self)
+	{
+		self.escape_attempts = self.escape_attempts.saturating_add(1);
+	}
+
+	/// Adds `count` of the given item to the bag so that it can be used in battle.
+	pub fn bag_add(&mut self, item: ItemType, count: ItemCountType)
+	{
+		let stock = self.bag.entry(item).or_insert(0);
+		*stock = stock.saturating_add(count);
+	}
+
+	/// The number of the given item left in the bag.
+	pub fn bag_count(&self, item: ItemType) -> ItemCountType
+	{
+		self.bag.get(&item).cloned().unwrap_or(0)
+	}
+
+	/// Every item in the bag with its count.
+	pub fn bag(&self) -> &BTreeMap<ItemType, ItemCountType>
+	{
+		&self.bag
+	}
+
+	/// Removes one of the given item from the bag.
+	pub fn bag_take(&mut self, item: ItemType)
+	{
+		let empty =
+		{
+			let stock = self.bag.entry(item).or_insert(0);
+			*stock = stock.saturating_sub(1);
+			*stock == 0
+		};
+		if empty
+		{
+			self.bag.remove(&item);
+		}
+	}
 	pub fn expose_add(&mut self, party: &Party, index: usize)
 	{
 		for active_self in &mut self.active
@@ -190,6 +413,19 @@ impl<'a> Party<'a>
 	{
 		self.members.len()
 	}
+
+	/// Returns the party members in the order they were in when the party was created.
+	///
+	/// Experience, health and attack limits gained or lost in battle are kept. For borrowed
+	/// parties, the members are copied.
+	///
+	pub fn into_members(self) -> Vec<Monster>
+	{
+		let mut members = self.reference_order.into_iter().zip(self.members.into_vec())
+			.collect::<Vec<_>>();
+		members.sort_by_key(|&(reference, _)| reference);
+		members.into_iter().map(|(_, member)| member).collect()
+	}
 	pub fn active_purge(&mut self)
 	{
 		// Decrease the number of active members if there is no one to take their place.
@@ -224,13 +460,36 @@ impl<'a> Party<'a>
 		})
 	}
 	pub fn member_experience_add(&mut self, index: usize, amount: ExperienceType)
+		-> MonsterLevelUp
 	{
 		self.members[index].experience_add(amount)
 	}
+	pub fn member_efforts_add(&mut self, index: usize, efforts: &[StatYieldType; STAT_COUNT])
+	{
+		self.members[index].efforts_add(efforts);
+	}
+	pub fn member_health_restore(&mut self, index: usize, amount: StatType)
+	{
+		self.members[index].health_gain(amount);
+	}
+	pub fn member_ailment_remove(&mut self, index: usize)
+	{
+		self.members[index].ailment_cure();
+	}
+	pub fn member_limit_restore(&mut self, index: usize, attack: usize, amount: LimitType)
+	{
+		self.members[index].attacks_mut()[attack].limit_left_restore(amount);
+	}
+	pub fn member_attack_learn(&mut self, index: usize, attack: AttackType, replace: Option<usize>)
+		-> bool
+	{
+		self.members[index].attack_learn(attack, replace)
+	}
 	pub fn switch_active(&mut self, member: usize, target: usize)
 	{
 		self.members.swap(self.active[member].member, target);
 		self.reference_order.swap(self.active[member].member, target);
+		self.active[member].volatile.clear();
 		if self.switch_waiting > 0
 		{
 			self.switch_waiting -= 1;
@@ -258,6 +517,7 @@ impl<'a> Party<'a>
 		{
 			member: &self.members[self.active[index].member],
 			modifiers: &self.active[index].modifiers,
+			volatile: &self.active[index].volatile,
 			priority: PartyMemberPriority::NORMAL
 		}
 	}
@@ -291,6 +551,12 @@ impl<'a> Party<'a>
 	{
 		self.reference_order[self.active[active].member]
 	}
+
+	/// The active index of the member with the given reference, if it is still active.
+	pub fn active_find_reference(&self, reference: usize) -> Option<usize>
+	{
+		(0..self.active.len()).find(|&active| self.active_member_reference(active) == reference)
+	}
 	pub fn iter(&self) -> slice::Iter<Monster>
 	{
 		self.members.iter()
@@ -305,9 +571,52 @@ impl<'a> Party<'a>
 		// self.active[index].as_mut().unwrap().modifiers.apply(modifiers);
 		self.active[index].modifiers.apply(modifiers);
 	}
+	/// Adds a volatile condition, replacing any existing condition of the same type.
+	pub fn active_member_volatile_add(&mut self, index: usize, volatile: Volatile)
+	{
+		let active = &mut self.active[index];
+		let volatile_type = volatile.volatile_type();
+		let position = active.volatile.iter().position(|existing|
+		{
+			existing.volatile_type() == volatile_type
+		});
+		match position
+		{
+			Some(position) => active.volatile[position] = volatile,
+			None => active.volatile.push(volatile),
+		}
+	}
+	pub fn active_member_volatile_remove(&mut self, index: usize, volatile_type: VolatileType)
+	{
+		self.active[index].volatile.retain(|volatile| volatile.volatile_type() != volatile_type);
+	}
+
+	/// Removes every volatile condition of active members caused by the given member reference of
+	/// the given party.
+	pub fn active_volatile_source_remove(&mut self, party: usize, reference: usize)
+	{
+		for active in &mut self.active
+		{
+			active.volatile.retain(|volatile| volatile.source() != Some((party, reference)));
+		}
+	}
+	/// Gives health to an active member. Members that fainted cannot be healed this way.
+	pub fn active_member_gain_health(&mut self, member: usize, amount: u16)
+	{
+		let target = self.members.get_mut(self.active[member].member).unwrap();
+		if target.health() != 0
+		{
+			target.health_gain(amount);
+		}
+	}
+	/// Takes health from an active member. Returns true only if this made the member faint.
 	pub fn active_member_lose_health(&mut self, member: usize, amount: u16) -> bool
 	{
 		let target = self.members.get_mut(self.active[member].member).unwrap();
+		if target.health() == 0
+		{
+			return false;
+		}
 		target.health_lose(amount);
 		if target.health() == 0
 		{
@@ -319,6 +628,39 @@ impl<'a> Party<'a>
 			false
 		}
 	}
+	pub fn active_member_ailment_set(&mut self, member: usize, ailment: Ailment, turns: u8)
+	{
+		let target = self.members.get_mut(self.active[member].member).unwrap();
+		target.ailment_set(ailment, turns);
+	}
+	pub fn active_member_ailment_cure(&mut self, member: usize)
+	{
+		let target = self.members.get_mut(self.active[member].member).unwrap();
+		target.ailment_cure();
+	}
+	pub fn active_member_ailment_turns_take(&mut self, member: usize)
+	{
+		let target = self.members.get_mut(self.active[member].member).unwrap();
+		target.ailment_turns_take();
+	}
+	pub fn active_member_held_item_take(&mut self, member: usize)
+	{
+		let target = self.members.get_mut(self.active[member].member).unwrap();
+		target.held_item_take();
+	}
+	pub fn active_member_recruit_set(&mut self, member: usize, method: RecruitMethod,
+		trainer: Option<Trainer>, location: Option<Location>, sub_location: Option<SubLocation>,
+		date: Option<DateType>)
+	{
+		let target = self.members.get_mut(self.active[member].member).unwrap();
+		target.recruit_set(method, location);
+		target.recruit_sub_location_set(sub_location);
+		target.recruit_date_set(date);
+		if let Some(trainer) = trainer
+		{
+			target.trainer_set(trainer);
+		}
+	}
 	pub fn active_member_attack_limit_take(&mut self, member: usize, attack: usize)
 	{
 		let target = self.members.get_mut(member).unwrap();
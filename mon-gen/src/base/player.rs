@@ -0,0 +1,224 @@
+use base::monster::Monster;
+use base::party::Party;
+use base::replay::{BattleReplay, BattleReplayStep};
+use base::runner::{BattleExecution, BattleRunner};
+
+/// Re-executes a recorded `BattleReplay` from the original party copy.
+///
+/// Every command and retreat sub-command is fed to a new `BattleRunner` as soon as it is ready for
+/// one, so the same effects and executions are generated in the same order as the original battle.
+/// Attacks learned in between commands are learned again before the next command is fed.
+///
+pub struct BattleReplayPlayer<'a, 'b>
+{
+	replay: &'b BattleReplay,
+	runner: BattleRunner<'a>,
+	command: usize,
+	sub_command: usize,
+	learn: usize,
+	step: usize,
+	verify: bool,
+	finished: bool,
+}
+
+impl<'a, 'b> BattleReplayPlayer<'a, 'b>
+{
+	/// Creates a new player for the given replay.
+	///
+	/// The `members` are the parties that are played with. These should be created using
+	/// `BattleReplay::party_members()`.
+	///
+	pub fn new(replay: &'b BattleReplay, members: &'a mut [Vec<Monster>]) -> Self
+	{
+		let parties = members.iter_mut().enumerate().map(|(index, party_members)|
+		{
+			let replay_party = replay.party(index);
+			let mut party = Party::new(party_members, replay_party.side(), replay_party.out(),
+				replay_party.gain_experience());
+			for &(item, count) in replay_party.bag()
+			{
+				party.bag_add(item, count);
+			}
+			if let Some(trainer) = replay_party.trainer()
+			{
+				party.trainer_set(trainer.clone());
+			}
+			party.kind_set(replay_party.kind());
+			party.experience_share_set(replay_party.experience_share());
+			party
+		}).collect();
+
+		let mut runner = BattleRunner::with_seed(parties, replay.seed());
+		if let Some(location) = replay.location()
+		{
+			runner.location_set(location);
+		}
+		if let Some(sub_location) = replay.sub_location()
+		{
+			runner.sub_location_set(sub_location);
+		}
+		if let Some(date) = replay.date()
+		{
+			runner.date_set(date);
+		}
+		if let Some(layout) = replay.layout()
+		{
+			runner.layout_set(layout);
+		}
+
+		BattleReplayPlayer
+		{
+			replay: replay,
+			runner: runner,
+			command: 0,
+			sub_command: 0,
+			learn: 0,
+			step: 0,
+			verify: false,
+			finished: false,
+		}
+	}
+
+	/// Creates a new player that panics if the playback diverges from the recorded battle.
+	///
+	/// See `new()` for details on the `members` parameter.
+	///
+	pub fn with_verify(replay: &'b BattleReplay, members: &'a mut [Vec<Monster>]) -> Self
+	{
+		let mut player = BattleReplayPlayer::new(replay, members);
+		player.verify = true;
+		player
+	}
+
+	/// The runner that is executing the replay.
+	pub fn runner(&self) -> &BattleRunner<'a>
+	{
+		&self.runner
+	}
+
+	/// Executes the next consecutive command effect. Returns `None` when the replay is over.
+	///
+	/// `BattleExecution::Ready` and `BattleExecution::RetreatWaiting` are never returned. Instead,
+	/// the next recorded command or sub-command is added and execution continues.
+	///
+	pub fn run(&mut self) -> Option<BattleExecution>
+	{
+		if self.finished
+		{
+			return None;
+		}
+		loop
+		{
+			let execution = self.runner.run();
+			match execution
+			{
+				BattleExecution::Ready =>
+				{
+					self.learn_pending();
+					if self.command < self.replay.command_count()
+					{
+						self.runner.command_add(self.replay.command(self.command).clone());
+						self.command += 1;
+						self.sub_command = 0;
+					}
+					else
+					{
+						return self.finish(None);
+					}
+				}
+				BattleExecution::RetreatWaiting(_) =>
+				{
+					if self.sub_command < self.replay.sub_command_count(self.command - 1)
+					{
+						let sub_command = *self.replay.sub_command(self.command - 1, self.sub_command);
+						self.runner.sub_command_add(sub_command);
+						self.sub_command += 1;
+					}
+					else
+					{
+						return self.finish(None);
+					}
+				}
+				BattleExecution::Finished(_) =>
+				{
+					// The outcome is only recorded if the battle was run until it was finished.
+					if self.step < self.replay.step_count()
+					{
+						if self.verify
+						{
+							self.verify_step(&execution);
+						}
+						self.step += 1;
+					}
+					return self.finish(Some(execution));
+				}
+				_ =>
+				{
+					if self.verify
+					{
+						self.verify_step(&execution);
+					}
+					self.step += 1;
+					return Some(execution);
+				}
+			}
+		}
+	}
+
+	/// Executes the replay until it is over.
+	pub fn run_all(&mut self)
+	{
+		while self.run().is_some() {}
+	}
+
+	// Learns the attacks that were learned before the next command was added.
+	fn learn_pending(&mut self)
+	{
+		while self.learn < self.replay.learn_count() &&
+			self.replay.learn(self.learn).command == self.command
+		{
+			let learn = *self.replay.learn(self.learn);
+			if !self.runner.member_attack_learn(learn.party, learn.member, learn.attack,
+				learn.replace) && self.verify
+			{
+				panic!("Replay diverged: attack {:?} could not be learned.", learn.attack);
+			}
+			self.learn += 1;
+		}
+	}
+
+	fn finish(&mut self, execution: Option<BattleExecution>) -> Option<BattleExecution>
+	{
+		self.finished = true;
+		if self.verify && self.step != self.replay.step_count()
+		{
+			panic!("Replay diverged: finished after {} steps but {} were recorded.", self.step,
+				self.replay.step_count());
+		}
+		execution
+	}
+
+	fn verify_step(&self, execution: &BattleExecution)
+	{
+		if self.step >= self.replay.step_count()
+		{
+			panic!("Replay diverged: step {} was not recorded.", self.step);
+		}
+
+		let effect = match *execution
+		{
+			BattleExecution::Command | BattleExecution::Finished(_) => None,
+			_ => Some(self.runner.current_effect().clone()),
+		};
+		let step = BattleReplayStep
+		{
+			execution: execution.clone(),
+			effect: effect,
+		};
+		if step != *self.replay.step(self.step)
+		{
+			panic!("Replay diverged at step {}: expected {:?} but found {:?}.", self.step,
+				self.replay.step(self.step), step);
+		}
+	}
+}
@@ -141,6 +141,12 @@ impl BattleQueue
 		self.waiting == 0
 	}
 
+	/// Returns true if no party has been given any commands yet.
+	pub fn empty(&self) -> bool
+	{
+		self.queue.iter().all(|party| party.ready == 0)
+	}
+
 	/// Returns the command for the indicated party member.
 	pub fn command_get(&self, party: usize, member: usize) -> Option<&CommandType>
 	{
@@ -1,11 +1,39 @@
 use std::io;
+use std::io::{Read, Write};
 
 use rand::Rng;
 use rand::os::OsRng;
+use toml;
 
 pub use base::command::CommandType;
 pub use base::effect::Effect;
-use base::command::CommandRetreat;
+use base::command::{CommandAttack, CommandEscape, CommandItem, CommandRecruit, CommandRetreat,
+	CommandSwitch};
+use base::monster::{Monster, Trainer};
+use base::party::{ExperienceShare, Party, PartyKind};
+use base::runner::BattleExecution;
+use base::serialize::*;
+use base::target::FieldLayout;
+use gen::ability_list::ABILITY_TABLE_CHECKSUM;
+use gen::attack_list::{AttackType, ATTACK_TABLE_CHECKSUM};
+use gen::item_list::{ItemType, ITEM_TABLE_CHECKSUM};
+use gen::locations::{Location, SubLocation};
+use gen::species_list::SPECIES_TABLE_CHECKSUM;
+use types::attack::AttackId;
+use types::battle::{ExperienceShareId, FieldLayoutId, PartyKindId};
+use types::generic::{DateType, LocationId, SubLocationId};
+use types::item::{ItemCountType, ItemId};
+use types::monster::RecruitMethodId;
+
+/// The current version of the replay file format.
+///
+/// Replays saved with a different version cannot be loaded. The version only changes once a
+/// released format changes, not while the format is extended before release.
+///
+pub const REPLAY_VERSION: u32 = 1;
+
+/// The first bytes of a binary replay file.
+const REPLAY_MAGIC: &'static [u8; 4] = b"MONR";
 
 struct BattleCommandInstance
 {
@@ -13,34 +41,197 @@ struct BattleCommandInstance
 	sub_command: Vec<Option<CommandRetreat>>,
 }
 
+/// A copy of a party as it was when the battle started.
+#[derive(Debug, Clone)]
+pub struct BattleReplayParty
+{
+	members: Vec<Monster>,
+	side: u8,
+	out: usize,
+	gain_experience: bool,
+	bag: Vec<(ItemType, ItemCountType)>,
+	trainer: Option<Trainer>,
+	kind: PartyKind,
+	experience_share: ExperienceShare,
+}
+
+impl BattleReplayParty
+{
+	pub fn new(members: Vec<Monster>, side: u8, out: usize, gain_experience: bool,
+		bag: Vec<(ItemType, ItemCountType)>, trainer: Option<Trainer>, kind: PartyKind,
+		experience_share: ExperienceShare) -> Self
+	{
+		BattleReplayParty
+		{
+			members: members,
+			side: side,
+			out: out,
+			gain_experience: gain_experience,
+			bag: bag,
+			trainer: trainer,
+			kind: kind,
+			experience_share: experience_share,
+		}
+	}
+	pub fn members(&self) -> &[Monster]
+	{
+		&self.members
+	}
+	pub fn side(&self) -> u8
+	{
+		self.side
+	}
+	pub fn out(&self) -> usize
+	{
+		self.out
+	}
+	pub fn gain_experience(&self) -> bool
+	{
+		self.gain_experience
+	}
+
+	/// Every item in the bag with its count.
+	pub fn bag(&self) -> &[(ItemType, ItemCountType)]
+	{
+		&self.bag
+	}
+
+	/// The trainer that owns the party, if any.
+	pub fn trainer(&self) -> Option<&Trainer>
+	{
+		self.trainer.as_ref()
+	}
+
+	/// Who controls the party.
+	pub fn kind(&self) -> PartyKind
+	{
+		self.kind
+	}
+
+	/// How experience is distributed between members.
+	pub fn experience_share(&self) -> ExperienceShare
+	{
+		self.experience_share
+	}
+}
+
+/// A single execution recorded during a battle.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BattleReplayStep
+{
+	/// The result of running the battle.
+	pub execution: BattleExecution,
+	/// The effect that was executed, if the execution executed one.
+	pub effect: Option<Effect>,
+}
+
+/// An attack learned by a party member between turns, such as after levelling up.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BattleReplayLearn
+{
+	/// The number of commands that were executed before the attack was learned.
+	pub command: usize,
+	pub party: usize,
+	pub member: usize,
+	pub attack: AttackType,
+	/// The index of the attack that was replaced, if any.
+	pub replace: Option<usize>,
+}
+
 /// Stores meta-data required to deterministically replay a battle sequence.
 pub struct BattleReplay
 {
 	effects: Vec<BattleCommandInstance>,
 
 	// Copy of original party.
+	parties: Vec<BattleReplayParty>,
+
+	// Outcome of the original battle, used to check that playback does not diverge.
+	steps: Vec<BattleReplayStep>,
+
+	learns: Vec<BattleReplayLearn>,
 
 	seed: usize,
+
+	location: Option<Location>,
+
+	sub_location: Option<SubLocation>,
+
+	date: Option<DateType>,
+
+	layout: Option<FieldLayout>,
 }
 
 impl BattleReplay
 {
+	/// Creates an empty replay with a randomly generated seed.
 	pub fn new() -> Result<Self, io::Error>
 	{
-		let replay = BattleReplay
+		Ok(BattleReplay::with_seed(OsRng::new()?.gen()))
+	}
+
+	/// Creates an empty replay using the given seed.
+	pub fn with_seed(seed: usize) -> Self
+	{
+		BattleReplay
 		{
-			seed: OsRng::new()?.gen(),
+			seed: seed,
 			// At minimum, a battle only has two command-effect pairs.
 			// Two sides versing, battle ends when one escapes.
 			effects: Vec::with_capacity(3),
-		};
-		// replay.command_add(BattleCommand::Turn, Vec::new());
-		Ok(replay)
+			parties: Vec::new(),
+			steps: Vec::new(),
+			learns: Vec::new(),
+			location: None,
+			sub_location: None,
+			date: None,
+			layout: None,
+		}
 	}
 	pub fn seed(&self) -> usize
 	{
 		self.seed
 	}
+
+	/// The location the battle takes place at, if it is known.
+	pub fn location(&self) -> Option<Location>
+	{
+		self.location
+	}
+	pub fn location_set(&mut self, location: Location)
+	{
+		self.location = Some(location);
+	}
+
+	/// The place within the location the battle takes place at, if it is known.
+	pub fn sub_location(&self) -> Option<SubLocation>
+	{
+		self.sub_location
+	}
+	pub fn sub_location_set(&mut self, sub_location: SubLocation)
+	{
+		self.sub_location = Some(sub_location);
+	}
+
+	/// The date the battle takes place on, if it is known.
+	pub fn date(&self) -> Option<DateType>
+	{
+		self.date
+	}
+	pub fn date_set(&mut self, date: DateType)
+	{
+		self.date = Some(date);
+	}
+
+	/// The field layout of the battle, if it was chosen instead of decided by the parties.
+	pub fn layout(&self) -> Option<FieldLayout>
+	{
+		self.layout
+	}
+	pub fn layout_set(&mut self, layout: FieldLayout)
+	{
+		self.layout = Some(layout);
+	}
 	pub fn command(&self, command: usize) -> &CommandType
 	{
 		&self.effects[command].command
@@ -69,4 +260,757 @@ impl BattleReplay
 	{
 		self.effects[command].sub_command.push(sub_command);
 	}
+	pub fn learn(&self, learn: usize) -> &BattleReplayLearn
+	{
+		&self.learns[learn]
+	}
+	pub fn learn_count(&self) -> usize
+	{
+		self.learns.len()
+	}
+	pub fn learn_add(&mut self, learn: BattleReplayLearn)
+	{
+		self.learns.push(learn);
+	}
+	pub fn party(&self, party: usize) -> &BattleReplayParty
+	{
+		&self.parties[party]
+	}
+	pub fn party_count(&self) -> usize
+	{
+		self.parties.len()
+	}
+	pub fn party_add(&mut self, party: &Party)
+	{
+		let out = party.active_count();
+		let bag = party.bag().iter().map(|(&item, &count)| (item, count)).collect();
+		self.parties.push(BattleReplayParty::new(party.iter().cloned().collect(), party.side(), out,
+			party.gain_experience(), bag, party.trainer().cloned(), party.kind(),
+			party.experience_share()));
+	}
+
+	/// Creates a copy of the members of every party as they were when the battle started.
+	///
+	/// Used to create the parties that a `BattleReplayPlayer` will play with.
+	///
+	pub fn party_members(&self) -> Vec<Vec<Monster>>
+	{
+		self.parties.iter().map(|party| party.members().to_vec()).collect()
+	}
+	pub fn step(&self, step: usize) -> &BattleReplayStep
+	{
+		&self.steps[step]
+	}
+	pub fn step_count(&self) -> usize
+	{
+		self.steps.len()
+	}
+	pub fn step_add(&mut self, execution: BattleExecution, effect: Option<Effect>)
+	{
+		self.steps.push(BattleReplayStep
+		{
+			execution: execution,
+			effect: effect,
+		});
+	}
+
+	/// Saves the seed, original parties and commands as TOML.
+	///
+	/// The recorded steps are not saved. Since battles are deterministic, they are regenerated by
+	/// playing back the loaded replay.
+	///
+	pub fn save_toml(&self, out: &mut Write) -> io::Result<()>
+	{
+		let mut table = toml::Table::new();
+		table.insert("version".to_string(), toml_int(REPLAY_VERSION));
+		table.insert("species_checksum".to_string(), toml::Value::String(checksum_to_string(
+			SPECIES_TABLE_CHECKSUM)));
+		table.insert("attack_checksum".to_string(), toml::Value::String(checksum_to_string(
+			ATTACK_TABLE_CHECKSUM)));
+		table.insert("ability_checksum".to_string(), toml::Value::String(checksum_to_string(
+			ABILITY_TABLE_CHECKSUM)));
+		table.insert("item_checksum".to_string(), toml::Value::String(checksum_to_string(
+			ITEM_TABLE_CHECKSUM)));
+		// TOML only has signed integers, so the seed is stored with the same bits.
+		table.insert("seed".to_string(), toml::Value::Integer(self.seed as i64));
+		if let Some(location) = self.location
+		{
+			table.insert("location".to_string(), toml_int(location as LocationId));
+		}
+		if let Some(sub_location) = self.sub_location
+		{
+			table.insert("sub_location".to_string(), toml_int(sub_location as SubLocationId));
+		}
+		if let Some(date) = self.date
+		{
+			table.insert("date".to_string(), toml_int(date));
+		}
+		if let Some(layout) = self.layout
+		{
+			table.insert("layout".to_string(), toml_int(layout as FieldLayoutId));
+		}
+
+		table.insert("party".to_string(), toml::Value::Array(self.parties.iter().map(|party|
+		{
+			let mut party_table = toml::Table::new();
+			party_table.insert("side".to_string(), toml_int(party.side));
+			party_table.insert("out".to_string(), toml::Value::Integer(party.out as i64));
+			party_table.insert("gain_experience".to_string(), toml::Value::Boolean(
+				party.gain_experience));
+			party_table.insert("kind".to_string(), toml_int(party.kind as PartyKindId));
+			party_table.insert("experience_share".to_string(), toml_int(party.experience_share as
+				ExperienceShareId));
+			if let Some(ref trainer) = party.trainer
+			{
+				party_table.insert("trainer".to_string(), toml::Value::Table(trainer.to_toml()));
+			}
+			party_table.insert("member".to_string(), toml::Value::Array(party.members.iter().map(
+				|member|
+			{
+				toml::Value::Table(member.to_toml())
+			}).collect()));
+			party_table.insert("bag".to_string(), toml::Value::Array(party.bag.iter().map(
+				|&(item, count)|
+			{
+				let mut item_table = toml::Table::new();
+				item_table.insert("item".to_string(), toml_int(item as ItemId));
+				item_table.insert("count".to_string(), toml_int(count));
+				toml::Value::Table(item_table)
+			}).collect()));
+			toml::Value::Table(party_table)
+		}).collect()));
+
+		table.insert("command".to_string(), toml::Value::Array(self.effects.iter().map(|instance|
+		{
+			let mut command_table = command_to_toml(&instance.command);
+			command_table.insert("retreat".to_string(), toml::Value::Array(
+				instance.sub_command.iter().map(|sub_command|
+			{
+				// Retreats that did not switch are stored as -1.
+				toml::Value::Integer(sub_command.map_or(-1, |retreat| retreat.target as i64))
+			}).collect()));
+			toml::Value::Table(command_table)
+		}).collect()));
+
+		table.insert("learn".to_string(), toml::Value::Array(self.learns.iter().map(|learn|
+		{
+			let mut learn_table = toml::Table::new();
+			learn_table.insert("command".to_string(), toml::Value::Integer(learn.command as i64));
+			learn_table.insert("party".to_string(), toml::Value::Integer(learn.party as i64));
+			learn_table.insert("member".to_string(), toml::Value::Integer(learn.member as i64));
+			learn_table.insert("attack".to_string(), toml_int(learn.attack as AttackId));
+			if let Some(replace) = learn.replace
+			{
+				learn_table.insert("replace".to_string(), toml::Value::Integer(replace as i64));
+			}
+			toml::Value::Table(learn_table)
+		}).collect()));
+
+		write!(out, "{}", toml::Value::Table(table))
+	}
+
+	/// Loads a replay saved with `save_toml`.
+	///
+	/// Fails if the replay was saved with a different format version or with different species,
+	/// attack, ability or item tables.
+	///
+	pub fn load_toml(input: &mut Read) -> SerializeResult<Self>
+	{
+		let table = try!(toml_read(input));
+
+		let version = try!(toml_get(&table, "version"));
+		if version != REPLAY_VERSION
+		{
+			return Err(SerializeError::VersionMismatch(version));
+		}
+		let species_checksum = try!(checksum_from_str(try!(toml_get_str(&table,
+			"species_checksum")), "species_checksum"));
+		let attack_checksum = try!(checksum_from_str(try!(toml_get_str(&table,
+			"attack_checksum")), "attack_checksum"));
+		let ability_checksum = try!(checksum_from_str(try!(toml_get_str(&table,
+			"ability_checksum")), "ability_checksum"));
+		let item_checksum = try!(checksum_from_str(try!(toml_get_str(&table, "item_checksum")),
+			"item_checksum"));
+		if species_checksum != SPECIES_TABLE_CHECKSUM || attack_checksum != ATTACK_TABLE_CHECKSUM ||
+			ability_checksum != ABILITY_TABLE_CHECKSUM || item_checksum != ITEM_TABLE_CHECKSUM
+		{
+			return Err(SerializeError::TableMismatch);
+		}
+
+		let seed = try!(table.get("seed").and_then(|value| value.as_integer()).map_or_else(||
+			invalid("seed"), Ok));
+		let mut replay = BattleReplay::with_seed(seed as usize);
+		if table.get("location").is_some()
+		{
+			replay.location = Some(try!(location_from_id(try!(toml_get(&table, "location")))));
+		}
+		if table.get("sub_location").is_some()
+		{
+			replay.sub_location = Some(try!(sub_location_from_id(try!(toml_get(&table,
+				"sub_location")))));
+		}
+		if table.get("date").is_some()
+		{
+			replay.date = Some(try!(toml_get(&table, "date")));
+		}
+		if table.get("layout").is_some()
+		{
+			replay.layout = Some(try!(field_layout_from_id(try!(toml_get(&table, "layout")))));
+		}
+
+		for party in try!(toml_get_array(&table, "party"))
+		{
+			let party_table = try!(toml_as_table(party, "party"));
+			let mut members = Vec::new();
+			for member in try!(toml_get_array(party_table, "member"))
+			{
+				members.push(try!(Monster::from_toml(try!(toml_as_table(member, "member")))));
+			}
+			let mut bag = Vec::new();
+			for item in try!(toml_get_array(party_table, "bag"))
+			{
+				let item_table = try!(toml_as_table(item, "bag"));
+				bag.push((try!(item_type_from_id(try!(toml_get(item_table, "item")))),
+					try!(toml_get(item_table, "count"))));
+			}
+			let trainer = match party_table.get("trainer")
+			{
+				Some(trainer) => Some(try!(Trainer::from_toml(try!(toml_as_table(trainer,
+					"trainer"))))),
+				None => None,
+			};
+			let kind = try!(party_kind_from_id(try!(toml_get(party_table, "kind"))));
+			let experience_share = try!(experience_share_from_id(try!(toml_get(party_table,
+				"experience_share"))));
+			replay.parties.push(BattleReplayParty::new(members, try!(toml_get(party_table, "side")),
+				try!(toml_get(party_table, "out")), try!(toml_get_bool(party_table,
+				"gain_experience")), bag, trainer, kind, experience_share));
+		}
+
+		for command in try!(toml_get_array(&table, "command"))
+		{
+			let command_table = try!(toml_as_table(command, "command"));
+			replay.command_add(try!(command_from_toml(command_table)));
+			let command_index = replay.effects.len() - 1;
+			for retreat in try!(toml_get_array(command_table, "retreat"))
+			{
+				let target = try!(retreat.as_integer().map_or_else(|| invalid("retreat"), Ok));
+				let sub_command = if target < 0
+				{
+					None
+				}
+				else
+				{
+					Some(CommandRetreat
+					{
+						target: try!(convert(target, "retreat")),
+					})
+				};
+				replay.sub_command_add(command_index, sub_command);
+			}
+		}
+
+		for learn in try!(toml_get_array(&table, "learn"))
+		{
+			let learn_table = try!(toml_as_table(learn, "learn"));
+			let replace = match learn_table.get("replace")
+			{
+				Some(_) => Some(try!(toml_get(learn_table, "replace"))),
+				None => None,
+			};
+			replay.learn_add(BattleReplayLearn
+			{
+				command: try!(toml_get(learn_table, "command")),
+				party: try!(toml_get(learn_table, "party")),
+				member: try!(toml_get(learn_table, "member")),
+				attack: try!(attack_type_from_id(try!(toml_get(learn_table, "attack")))),
+				replace: replace,
+			});
+		}
+
+		try!(replay.validate());
+		Ok(replay)
+	}
+
+	/// Saves the same data as `save_toml` in a compact binary format.
+	pub fn save_binary(&self, out: &mut Write) -> io::Result<()>
+	{
+		try!(out.write_all(REPLAY_MAGIC));
+		try!(write_u32(out, REPLAY_VERSION));
+		try!(write_u64(out, SPECIES_TABLE_CHECKSUM));
+		try!(write_u64(out, ATTACK_TABLE_CHECKSUM));
+		try!(write_u64(out, ABILITY_TABLE_CHECKSUM));
+		try!(write_u64(out, ITEM_TABLE_CHECKSUM));
+		try!(write_u64(out, self.seed as u64));
+		match self.location
+		{
+			Some(location) =>
+			{
+				try!(write_u8(out, 1));
+				try!(write_u8(out, location as LocationId));
+			}
+			None => try!(write_u8(out, 0)),
+		}
+		match self.sub_location
+		{
+			Some(sub_location) =>
+			{
+				try!(write_u8(out, 1));
+				try!(write_u8(out, sub_location as SubLocationId));
+			}
+			None => try!(write_u8(out, 0)),
+		}
+		match self.date
+		{
+			Some(date) =>
+			{
+				try!(write_u8(out, 1));
+				try!(write_u32(out, date));
+			}
+			None => try!(write_u8(out, 0)),
+		}
+		match self.layout
+		{
+			Some(layout) =>
+			{
+				try!(write_u8(out, 1));
+				try!(write_u8(out, layout as FieldLayoutId));
+			}
+			None => try!(write_u8(out, 0)),
+		}
+
+		try!(write_u32(out, self.parties.len() as u32));
+		for party in &self.parties
+		{
+			try!(write_u8(out, party.side));
+			try!(write_u32(out, party.out as u32));
+			try!(write_u8(out, party.gain_experience as u8));
+			try!(write_u8(out, party.kind as PartyKindId));
+			try!(write_u8(out, party.experience_share as ExperienceShareId));
+			try!(write_u32(out, party.members.len() as u32));
+			for member in &party.members
+			{
+				try!(member.write_binary(out));
+			}
+			try!(write_u32(out, party.bag.len() as u32));
+			for &(item, count) in &party.bag
+			{
+				try!(write_u16(out, item as ItemId));
+				try!(write_u8(out, count));
+			}
+			match party.trainer
+			{
+				Some(ref trainer) =>
+				{
+					try!(write_u8(out, 1));
+					try!(trainer.write_binary(out));
+				}
+				None => try!(write_u8(out, 0)),
+			}
+		}
+
+		try!(write_u32(out, self.effects.len() as u32));
+		for instance in &self.effects
+		{
+			try!(command_write_binary(&instance.command, out));
+			try!(write_u32(out, instance.sub_command.len() as u32));
+			for sub_command in &instance.sub_command
+			{
+				match *sub_command
+				{
+					Some(ref retreat) =>
+					{
+						try!(write_u8(out, 1));
+						try!(write_u32(out, retreat.target as u32));
+					}
+					None => try!(write_u8(out, 0)),
+				}
+			}
+		}
+
+		try!(write_u32(out, self.learns.len() as u32));
+		for learn in &self.learns
+		{
+			try!(write_u32(out, learn.command as u32));
+			try!(write_u32(out, learn.party as u32));
+			try!(write_u32(out, learn.member as u32));
+			try!(write_u16(out, learn.attack as AttackId));
+			match learn.replace
+			{
+				Some(replace) =>
+				{
+					try!(write_u8(out, 1));
+					try!(write_u32(out, replace as u32));
+				}
+				None => try!(write_u8(out, 0)),
+			}
+		}
+		Ok(())
+	}
+
+	/// Loads a replay saved with `save_binary`.
+	///
+	/// See `load_toml` for when loading fails.
+	///
+	pub fn load_binary(input: &mut Read) -> SerializeResult<Self>
+	{
+		let mut magic = [0u8; 4];
+		try!(input.read_exact(&mut magic));
+		if magic != *REPLAY_MAGIC
+		{
+			return invalid("magic");
+		}
+
+		let version = try!(read_u32(input));
+		if version != REPLAY_VERSION
+		{
+			return Err(SerializeError::VersionMismatch(version));
+		}
+		let species_checksum = try!(read_u64(input));
+		let attack_checksum = try!(read_u64(input));
+		let ability_checksum = try!(read_u64(input));
+		let item_checksum = try!(read_u64(input));
+		if species_checksum != SPECIES_TABLE_CHECKSUM || attack_checksum != ATTACK_TABLE_CHECKSUM ||
+			ability_checksum != ABILITY_TABLE_CHECKSUM || item_checksum != ITEM_TABLE_CHECKSUM
+		{
+			return Err(SerializeError::TableMismatch);
+		}
+
+		let mut replay = BattleReplay::with_seed(try!(read_u64(input)) as usize);
+		if try!(read_u8(input)) != 0
+		{
+			replay.location = Some(try!(location_from_id(try!(read_u8(input)))));
+		}
+		if try!(read_u8(input)) != 0
+		{
+			replay.sub_location = Some(try!(sub_location_from_id(try!(read_u8(input)))));
+		}
+		if try!(read_u8(input)) != 0
+		{
+			replay.date = Some(try!(read_u32(input)));
+		}
+		if try!(read_u8(input)) != 0
+		{
+			replay.layout = Some(try!(field_layout_from_id(try!(read_u8(input)))));
+		}
+
+		let party_count = try!(read_u32(input));
+		for _ in 0..party_count
+		{
+			let side = try!(read_u8(input));
+			let out = try!(read_u32(input)) as usize;
+			let gain_experience = try!(read_u8(input)) != 0;
+			let kind = try!(party_kind_from_id(try!(read_u8(input))));
+			let experience_share = try!(experience_share_from_id(try!(read_u8(input))));
+			let member_count = try!(read_u32(input));
+			let mut members = Vec::new();
+			for _ in 0..member_count
+			{
+				members.push(try!(Monster::read_binary(input)));
+			}
+			let bag_count = try!(read_u32(input));
+			let mut bag = Vec::new();
+			for _ in 0..bag_count
+			{
+				bag.push((try!(item_type_from_id(try!(read_u16(input)))), try!(read_u8(input))));
+			}
+			let trainer = if try!(read_u8(input)) != 0
+			{
+				Some(try!(Trainer::read_binary(input)))
+			}
+			else
+			{
+				None
+			};
+			replay.parties.push(BattleReplayParty::new(members, side, out, gain_experience, bag,
+				trainer, kind, experience_share));
+		}
+
+		let command_count = try!(read_u32(input));
+		for command_index in 0..command_count as usize
+		{
+			replay.command_add(try!(command_read_binary(input)));
+			let sub_command_count = try!(read_u32(input));
+			for _ in 0..sub_command_count
+			{
+				let sub_command = if try!(read_u8(input)) != 0
+				{
+					Some(CommandRetreat
+					{
+						target: try!(read_u32(input)) as usize,
+					})
+				}
+				else
+				{
+					None
+				};
+				replay.sub_command_add(command_index, sub_command);
+			}
+		}
+
+		let learn_count = try!(read_u32(input));
+		for _ in 0..learn_count
+		{
+			let command = try!(read_u32(input)) as usize;
+			let party = try!(read_u32(input)) as usize;
+			let member = try!(read_u32(input)) as usize;
+			let attack = try!(attack_type_from_id(try!(read_u16(input))));
+			let replace = if try!(read_u8(input)) != 0
+			{
+				Some(try!(read_u32(input)) as usize)
+			}
+			else
+			{
+				None
+			};
+			replay.learn_add(BattleReplayLearn
+			{
+				command: command,
+				party: party,
+				member: member,
+				attack: attack,
+				replace: replace,
+			});
+		}
+
+		try!(replay.validate());
+		Ok(replay)
+	}
+
+	// Checks that commands and learned attacks only refer to existing parties and members.
+	fn validate(&self) -> SerializeResult<()>
+	{
+		let party_valid = |party: usize| party < self.parties.len();
+		let member_valid = |party: usize, member: usize|
+		{
+			party_valid(party) && member < self.parties[party].members.len()
+		};
+		for instance in &self.effects
+		{
+			let valid = match instance.command
+			{
+				CommandType::Attack(ref attack) =>
+				{
+					member_valid(attack.party, attack.member) &&
+						member_valid(attack.target_party, attack.target_member)
+				}
+				CommandType::Item(ref item) =>
+				{
+					member_valid(item.party, item.member) && member_valid(item.party, item.target)
+				}
+				CommandType::Recruit(ref recruit) =>
+				{
+					member_valid(recruit.party, recruit.member) &&
+						member_valid(recruit.target_party, recruit.target_member)
+				}
+				CommandType::Switch(ref switch) =>
+				{
+					member_valid(switch.party, switch.member) &&
+						member_valid(switch.party, switch.target)
+				}
+				CommandType::Escape(ref escape) => party_valid(escape.party),
+				CommandType::Turn => true,
+			};
+			if !valid
+			{
+				return invalid("command");
+			}
+		}
+		for (index, learn) in self.learns.iter().enumerate()
+		{
+			// Attacks are learned in order, in between commands.
+			if !member_valid(learn.party, learn.member) || learn.command > self.effects.len() ||
+				(index != 0 && learn.command < self.learns[index - 1].command)
+			{
+				return invalid("learn");
+			}
+		}
+		Ok(())
+	}
+}
+
+fn command_to_toml(command: &CommandType) -> toml::Table
+{
+	let mut table = toml::Table::new();
+	let kind =
+	{
+		let mut insert = |key: &str, value: usize|
+		{
+			table.insert(key.to_string(), toml::Value::Integer(value as i64));
+		};
+		match *command
+		{
+			CommandType::Attack(ref attack) =>
+			{
+				insert("party", attack.party);
+				insert("member", attack.member);
+				insert("attack_index", attack.attack_index);
+				insert("target_party", attack.target_party);
+				insert("target_member", attack.target_member);
+				"attack"
+			}
+			CommandType::Item(ref item) =>
+			{
+				insert("party", item.party);
+				insert("member", item.member);
+				insert("item", item.item as usize);
+				insert("target", item.target);
+				"item"
+			}
+			CommandType::Recruit(ref recruit) =>
+			{
+				insert("party", recruit.party);
+				insert("member", recruit.member);
+				insert("method", recruit.method as usize);
+				insert("target_party", recruit.target_party);
+				insert("target_member", recruit.target_member);
+				"recruit"
+			}
+			CommandType::Switch(ref switch) =>
+			{
+				insert("party", switch.party);
+				insert("member", switch.member);
+				insert("target", switch.target);
+				"switch"
+			}
+			CommandType::Escape(ref escape) =>
+			{
+				insert("party", escape.party);
+				"escape"
+			}
+			CommandType::Turn => "turn",
+		}
+	};
+	table.insert("kind".to_string(), toml::Value::String(kind.to_string()));
+	table
+}
+
+fn command_from_toml(table: &toml::Table) -> SerializeResult<CommandType>
+{
+	match try!(toml_get_str(table, "kind"))
+	{
+		"attack" => Ok(CommandType::Attack(CommandAttack
+		{
+			party: try!(toml_get(table, "party")),
+			member: try!(toml_get(table, "member")),
+			attack_index: try!(toml_get(table, "attack_index")),
+			target_party: try!(toml_get(table, "target_party")),
+			target_member: try!(toml_get(table, "target_member")),
+		})),
+		"item" => Ok(CommandType::Item(CommandItem
+		{
+			party: try!(toml_get(table, "party")),
+			member: try!(toml_get(table, "member")),
+			item: try!(item_type_from_id(try!(toml_get(table, "item")))),
+			target: try!(toml_get(table, "target")),
+		})),
+		"recruit" => Ok(CommandType::Recruit(CommandRecruit
+		{
+			party: try!(toml_get(table, "party")),
+			member: try!(toml_get(table, "member")),
+			method: try!(recruit_method_from_id(try!(toml_get(table, "method")))),
+			target_party: try!(toml_get(table, "target_party")),
+			target_member: try!(toml_get(table, "target_member")),
+		})),
+		"switch" => Ok(CommandType::Switch(CommandSwitch
+		{
+			party: try!(toml_get(table, "party")),
+			member: try!(toml_get(table, "member")),
+			target: try!(toml_get(table, "target")),
+		})),
+		"escape" => Ok(CommandType::Escape(CommandEscape
+		{
+			party: try!(toml_get(table, "party")),
+		})),
+		"turn" => Ok(CommandType::Turn),
+		_ => invalid("kind"),
+	}
+}
+
+fn command_write_binary(command: &CommandType, out: &mut Write) -> io::Result<()>
+{
+	match *command
+	{
+		CommandType::Attack(ref attack) =>
+		{
+			try!(write_u8(out, 0));
+			try!(write_u32(out, attack.party as u32));
+			try!(write_u32(out, attack.member as u32));
+			try!(write_u32(out, attack.attack_index as u32));
+			try!(write_u32(out, attack.target_party as u32));
+			write_u32(out, attack.target_member as u32)
+		}
+		CommandType::Switch(ref switch) =>
+		{
+			try!(write_u8(out, 1));
+			try!(write_u32(out, switch.party as u32));
+			try!(write_u32(out, switch.member as u32));
+			write_u32(out, switch.target as u32)
+		}
+		CommandType::Escape(ref escape) =>
+		{
+			try!(write_u8(out, 2));
+			write_u32(out, escape.party as u32)
+		}
+		CommandType::Turn => write_u8(out, 3),
+		CommandType::Item(ref item) =>
+		{
+			try!(write_u8(out, 4));
+			try!(write_u32(out, item.party as u32));
+			try!(write_u32(out, item.member as u32));
+			try!(write_u16(out, item.item as ItemId));
+			write_u32(out, item.target as u32)
+		}
+		CommandType::Recruit(ref recruit) =>
+		{
+			try!(write_u8(out, 5));
+			try!(write_u32(out, recruit.party as u32));
+			try!(write_u32(out, recruit.member as u32));
+			try!(write_u8(out, recruit.method as RecruitMethodId));
+			try!(write_u32(out, recruit.target_party as u32));
+			write_u32(out, recruit.target_member as u32)
+		}
+	}
+}
+
+fn command_read_binary(input: &mut Read) -> SerializeResult<CommandType>
+{
+	match try!(read_u8(input))
+	{
+		0 => Ok(CommandType::Attack(CommandAttack
+		{
+			party: try!(read_u32(input)) as usize,
+			member: try!(read_u32(input)) as usize,
+			attack_index: try!(read_u32(input)) as usize,
+			target_party: try!(read_u32(input)) as usize,
+			target_member: try!(read_u32(input)) as usize,
+		})),
+		1 => Ok(CommandType::Switch(CommandSwitch
+		{
+			party: try!(read_u32(input)) as usize,
+			member: try!(read_u32(input)) as usize,
+			target: try!(read_u32(input)) as usize,
+		})),
+		2 => Ok(CommandType::Escape(CommandEscape
+		{
+			party: try!(read_u32(input)) as usize,
+		})),
+		3 => Ok(CommandType::Turn),
+		4 => Ok(CommandType::Item(CommandItem
+		{
+			party: try!(read_u32(input)) as usize,
+			member: try!(read_u32(input)) as usize,
+			item: try!(item_type_from_id(try!(read_u16(input)))),
+			target: try!(read_u32(input)) as usize,
+		})),
+		5 => Ok(CommandType::Recruit(CommandRecruit
+		{
+			party: try!(read_u32(input)) as usize,
+			member: try!(read_u32(input)) as usize,
+			method: try!(recruit_method_from_id(try!(read_u8(input)))),
+			target_party: try!(read_u32(input)) as usize,
+			target_member: try!(read_u32(input)) as usize,
+		})),
+		_ => invalid("command"),
+	}
 }
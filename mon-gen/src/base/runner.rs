@@ -3,12 +3,21 @@ use std::collections::HashMap;
 use std::collections::VecDeque;
 use std::io;
 
-use base::command::{CommandType, CommandRetreat};
-use base::effect::{Effect, ExperienceGain, Lingering, LingeringChange, NoneReason};
+use base::command::{CommandType, CommandItem, CommandRetreat};
+use base::effect::{Effect, EffortGain, ExperienceGain, LevelUp, Lingering, LingeringChange,
+	NoneReason};
 use base::party::Party;
-use base::replay::BattleReplay;
-use calculate::experience::{MemberIndex, calculate_experience};
+use base::replay::{BattleReplay, BattleReplayLearn};
+use base::target::FieldLayout;
+use calculate::ability;
+use calculate::ailment;
+use calculate::experience::{MemberIndex, calculate_efforts, calculate_experience};
+use calculate::item;
 use calculate::lingering::LingeringType;
+use calculate::volatile;
+use gen::attack_list::AttackType;
+use gen::locations::{Location, SubLocation};
+use types::generic::DateType;
 
 
 // The battle flags value type for `BattleFlags`.
@@ -26,14 +35,32 @@ impl BattleFlags
 	pub const SPEED_REVERSE: BattleFlagsType = 0b10;
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct BattlePartyMember
 {
 	pub party: usize,
 	pub member: usize,
 }
 
-#[derive(Debug, PartialEq, Eq)]
+/// How a finished battle ended.
+///
+/// When the last members of several sides faint during the same command, the side whose member
+/// used the command wins. This is how an attacker that faints from its own attack, such as from
+/// exploding, still wins the battle. Otherwise, such as when members faint from effects at the end
+/// of a turn, the battle is a draw.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BattleOutcome
+{
+	/// The given side is the last one standing or recruited a member.
+	Win(u8),
+	/// Every remaining side was defeated at the same time.
+	Draw,
+	/// The given party escaped from the battle.
+	Escape(usize),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum BattleExecution
 {
 	/// A new command has been executed. An effect for this command has not been executed yet.
@@ -51,30 +78,35 @@ pub enum BattleExecution
 	/// A party member has requested to be switched out.
 	RetreatWaiting(BattlePartyMember),
 	/// Occurs when the battle is over. Further commands cannot be added or processed.
-	Finished(u8),
+	Finished(BattleOutcome),
 }
 
 pub struct BattleState<'a>
 {
 	parties: Vec<Party<'a>>,
-	// replay: BattleReplay,
 	flags: BattleFlagsType,
 	lingering: Vec<LingeringType>,
+	layout: FieldLayout,
 }
 
 impl<'a> BattleState<'a>
 {
-	fn new(parties: Vec<Party<'a>>) -> Result<Self, io::Error>
+	fn new(parties: Vec<Party<'a>>) -> Self
 	{
-		// let replay = BattleReplay::new()?;
-
-		Ok(BattleState
+		let mut state = BattleState
 		{
 			parties: parties,
-			// replay: replay,
 			flags: 0,
 			lingering: Vec::new(),
-		})
+			layout: FieldLayout::Single,
+		};
+		let count = (0..state.parties.len()).map(|party|
+		{
+			let side = state.parties[party].side();
+			state.side_active_count(side)
+		}).max().unwrap_or(0);
+		state.layout = FieldLayout::with_active(count);
+		state
 	}
 
 	pub fn parties(&self) -> &[Party]
@@ -91,6 +123,50 @@ impl<'a> BattleState<'a>
 	{
 		&self.lingering
 	}
+
+	/// How active members are arranged on the field.
+	pub fn layout(&self) -> FieldLayout
+	{
+		self.layout
+	}
+
+	/// The number of active members on the given side, across every party on that side.
+	pub fn side_active_count(&self, side: u8) -> usize
+	{
+		self.parties.iter().filter(|party| party.side() == side).fold(0,
+			|count, party| count + party.active_count())
+	}
+
+	/// The position of an active member among every active member on the same side.
+	///
+	/// Parties on the same side stand next to each other in the order they were added, so the
+	/// active members of earlier parties have lower positions.
+	///
+	pub fn position(&self, party: usize, active: usize) -> usize
+	{
+		let side = self.parties[party].side();
+		self.parties[..party].iter().filter(|other| other.side() == side).fold(active,
+			|position, other| position + other.active_count())
+	}
+
+	/// The position of an active member on the field, counted from the left of its side.
+	///
+	/// Unlike `position`, this takes into account members shifting towards the center as the side
+	/// shrinks. See `FieldLayout::position`.
+	///
+	pub fn field_position(&self, party: usize, active: usize) -> usize
+	{
+		let count = self.side_active_count(self.parties[party].side());
+		self.layout.position(self.position(party, active), count)
+	}
+
+	/// Whether two active members are next to or across from each other on the field.
+	pub fn is_adjacent(&self, party: usize, active: usize, target_party: usize,
+		target_active: usize) -> bool
+	{
+		self.layout.is_adjacent(self.field_position(party, active),
+			self.field_position(target_party, target_active))
+	}
 //     Fn command_previous(&self)
 //     Fn command_previous_member(&self, party, member)
 
@@ -105,6 +181,11 @@ impl<'a> BattleState<'a>
 		self.flags = flags
 	}
 
+	fn layout_set(&mut self, layout: FieldLayout)
+	{
+		self.layout = layout;
+	}
+
 	fn lingering_add(&mut self, lingering: LingeringType)
 	{
 		self.lingering.push(lingering);
@@ -173,20 +254,47 @@ pub struct BattleRunner<'a>
 	sub_command: usize,
 	turn: usize,
 	sides_alive: HashMap<u8, usize>,
+	// The sides that were defeated during the current command.
+	sides_defeated: Vec<u8>,
 	party_switch_waiting: usize,
 	effect_current: Effect,
 	retreat: bool,
+	// The side of the party that recruited a member, ending the battle.
+	recruited: Option<u8>,
+	// The party that escaped, ending the battle.
+	escaped: Option<usize>,
 	// flags: BattleFlagsType,
 }
 
 impl<'a> BattleRunner<'a>
 {
 	/// Generates a new battle object with a randomly generated RNG and empty command history.
-	pub fn new(mut parties: Vec<Party<'a>>) -> Result<Self, io::Error>
+	pub fn new(parties: Vec<Party<'a>>) -> Result<Self, io::Error>
 	{
 		let replay = BattleReplay::new()?;
+		Ok(BattleRunner::with_replay(parties, replay))
+	}
+
+	/// Generates a new battle object with an RNG seeded by `seed` and empty command history.
+	///
+	/// Two battles created with the same seed, parties and commands will always produce the same
+	/// effects.
+	///
+	pub fn with_seed(parties: Vec<Party<'a>>, seed: usize) -> Self
+	{
+		BattleRunner::with_replay(parties, BattleReplay::with_seed(seed))
+	}
+
+	fn with_replay(mut parties: Vec<Party<'a>>, mut replay: BattleReplay) -> Self
+	{
 		let rng = StdRng::from_seed(&[replay.seed()]);
 
+		// Keep a copy of every party before anything happens so the battle can be replayed.
+		for party in &parties
+		{
+			replay.party_add(party);
+		}
+
 		// Expose active parties for experience gaining.
 		// Done after code that can return Result::Err so it's not done needlessly.
 		let mut sides = HashMap::new();
@@ -201,9 +309,9 @@ impl<'a> BattleRunner<'a>
 			BattleRunner::expose_party(&mut parties, party_index);
 		}
 
-		Ok(BattleRunner
+		BattleRunner
 		{
-			state: BattleState::new(parties)?,
+			state: BattleState::new(parties),
 			effects: BattleEffects::new(),
 			replay: replay,
 			rng: rng,
@@ -211,10 +319,13 @@ impl<'a> BattleRunner<'a>
 			sub_command: 0,
 			turn: 0,
 			sides_alive: sides,
+			sides_defeated: Vec::new(),
 			party_switch_waiting: 0,
 			effect_current: Effect::None(NoneReason::None),
 			retreat: false,
-		})
+			recruited: None,
+			escaped: None,
+		}
 	}
 
 	pub fn state(&self) -> &BattleState
@@ -222,6 +333,18 @@ impl<'a> BattleRunner<'a>
 		&self.state
 	}
 
+	/// Consumes the runner and returns its parties.
+	pub fn into_parties(self) -> Vec<Party<'a>>
+	{
+		self.state.parties
+	}
+
+	/// The recorded seed and command history for this battle.
+	pub fn replay(&self) -> &BattleReplay
+	{
+		&self.replay
+	}
+
 	/// The current executing command.
 	///
 	/// This method should not be called before run() is called. At this point, no commands exist
@@ -241,6 +364,44 @@ impl<'a> BattleRunner<'a>
 		&self.effect_current
 	}
 
+	/// Whether using the item described by `command` would have any effect on its target.
+	pub fn item_effective(&self, command: &CommandItem) -> bool
+	{
+		// Use a copy of the generator so checking does not change the outcome of the battle.
+		let mut rng = self.rng.clone();
+		let mut effects = BattleEffects::new();
+		command.item.use_effects(&mut effects, command, &self.state, &mut rng);
+		!effects.effects_empty()
+	}
+
+	/// Sets the location the battle takes place at. Recruited members remember this location.
+	pub fn location_set(&mut self, location: Location)
+	{
+		self.replay.location_set(location);
+	}
+
+	/// Sets the place within the location the battle takes place at, such as grass or water.
+	pub fn sub_location_set(&mut self, sub_location: SubLocation)
+	{
+		self.replay.sub_location_set(sub_location);
+	}
+
+	/// Sets the date the battle takes place on. Recruited members remember this date.
+	pub fn date_set(&mut self, date: DateType)
+	{
+		self.replay.date_set(date);
+	}
+
+	/// Sets how active members are arranged on the field.
+	///
+	/// By default, the layout has as many positions as the side with the most active members.
+	///
+	pub fn layout_set(&mut self, layout: FieldLayout)
+	{
+		self.state.layout_set(layout);
+		self.replay.layout_set(layout);
+	}
+
 	pub fn command_add(&mut self, command: CommandType)
 	{
 		self.replay.command_add(command);
@@ -251,6 +412,29 @@ impl<'a> BattleRunner<'a>
 		self.replay.sub_command_add(self.command - 1, sub_command);
 	}
 
+	/// Teaches an attack to a party member in between commands. See `Monster::attack_learn`.
+	///
+	/// Learned attacks are recorded in the replay so that they are learned again during playback.
+	///
+	pub fn member_attack_learn(&mut self, party: usize, member: usize, attack: AttackType,
+		replace: Option<usize>) -> bool
+	{
+		if !self.state.parties_mut()[party].member_attack_learn(member, attack, replace)
+		{
+			return false;
+		}
+		let command = self.replay.command_count();
+		self.replay.learn_add(BattleReplayLearn
+		{
+			command: command,
+			party: party,
+			member: member,
+			attack: attack,
+			replace: replace,
+		});
+		true
+	}
+
 	/// Executes the next consecutive command effect. Returns the result of the command.
 	///
 	/// Execution goes as follows:
@@ -258,7 +442,57 @@ impl<'a> BattleRunner<'a>
 	/// - Checks are done to see if there is a winner yet.
 	/// - The next command is lined up for execution.
 	///
+	/// Commands and effects that were executed are recorded in the replay.
+	///
 	pub fn run(&mut self) -> BattleExecution
+	{
+		let execution = self.run_execution();
+		match execution
+		{
+			BattleExecution::Command =>
+			{
+				self.replay.step_add(execution.clone(), None);
+			}
+			BattleExecution::Effect | BattleExecution::Death(_) =>
+			{
+				self.replay.step_add(execution.clone(), Some(self.effect_current.clone()));
+			}
+			BattleExecution::Finished(_) =>
+			{
+				// A finished battle keeps returning the same execution, so it is only recorded once.
+				let count = self.replay.step_count();
+				if count == 0 || self.replay.step(count - 1).execution != execution
+				{
+					self.replay.step_add(execution.clone(), None);
+				}
+			}
+			_ => {}
+		}
+		execution
+	}
+
+	// The outcome of the battle once at most one side is left. See `BattleOutcome`.
+	fn outcome(&self) -> BattleOutcome
+	{
+		if let Some(side) = self.sides_alive.keys().next()
+		{
+			return BattleOutcome::Win(*side);
+		}
+		if self.command != 0
+		{
+			if let CommandType::Attack(ref command) = *self.replay.command(self.command - 1)
+			{
+				let side = self.state.parties()[command.party].side();
+				if self.sides_defeated.contains(&side)
+				{
+					return BattleOutcome::Win(side);
+				}
+			}
+		}
+		BattleOutcome::Draw
+	}
+
+	fn run_execution(&mut self) -> BattleExecution
 	{
 		if (self.command != 0 && !self.effects.effects_empty()) || self.retreat
 		{
@@ -286,15 +520,23 @@ impl<'a> BattleRunner<'a>
 				execution
 			}
 		}
+		else if let Some(party) = self.escaped
+		{
+			BattleExecution::Finished(BattleOutcome::Escape(party))
+		}
+		else if let Some(side) = self.recruited
+		{
+			BattleExecution::Finished(BattleOutcome::Win(side))
+		}
 		else if self.sides_alive.len() <= 1
 		{
-			// TODO: Handle ties.
-			BattleExecution::Finished(*self.sides_alive.keys().next().unwrap())
+			BattleExecution::Finished(self.outcome())
 		}
 		else if self.command < self.replay.command_count()
 		{
 			self.effects.effects_clear();
 			self.sub_command = 0;
+			self.sides_defeated.clear();
 			
 			// TODO: Refactor to use a match here.
 			if let CommandType::Turn = *self.replay.command(self.command)
@@ -322,10 +564,16 @@ impl<'a> BattleRunner<'a>
 						self.effects.effect_add(Effect::LingeringChange(lingering_change));
 					}
 				}
+				ailment::turn_end(&mut self.effects, &self.state, &mut self.rng);
+				volatile::turn_end(&mut self.effects, &self.state);
+				ability::turn_end(&mut self.effects, &self.state, &mut self.rng);
+				item::turn_end(&mut self.effects, &self.state, &mut self.rng);
 				self.turn += 1;
 			}
 			else
 			{
+				let mut blocked = None;
+				let mut cancelled = false;
 				let hit = if let CommandType::Attack(ref attack_command) = *self.replay.command(self.command)
 				{
 					let hit =
@@ -333,9 +581,28 @@ impl<'a> BattleRunner<'a>
 						self.state.parties()[attack_command.target_party].active_member_alive(attack_command.target_member).is_some()
 					};
 
-					let party = &mut self.state.parties_mut()[attack_command.party];
-					party.active_member_attack_limit_take(attack_command.member,
-						attack_command.attack_index);
+					// Ailments may keep the party member from attacking at all.
+					{
+						let offending = self.state.parties()[attack_command.party].active_member(attack_command.member);
+						if ailment::blocks_attack(&offending, &mut self.rng)
+						{
+							blocked = offending.member.ailment();
+						}
+					}
+
+					// Volatile conditions may replace or cancel the attack.
+					if blocked.is_none()
+					{
+						cancelled = !volatile::before_attack(&mut self.effects, attack_command,
+							&self.state, &mut self.rng);
+					}
+
+					if blocked.is_none() && !cancelled
+					{
+						let party = &mut self.state.parties_mut()[attack_command.party];
+						party.active_member_attack_limit_take(attack_command.member,
+							attack_command.attack_index);
+					}
 
 					hit
 				}
@@ -344,7 +611,15 @@ impl<'a> BattleRunner<'a>
 					true
 				};
 
-				if hit
+				if let Some(blocked_ailment) = blocked
+				{
+					self.effects.effect_add(Effect::None(NoneReason::Ailment(blocked_ailment)));
+				}
+				else if cancelled
+				{
+					// Replacing effects were already added.
+				}
+				else if hit
 				{
 					self.replay.command(self.command).effects(&mut self.effects, &self.state, &mut self.rng);
 				}
@@ -369,36 +644,60 @@ impl<'a> BattleRunner<'a>
 	{
 		let member = target_active;
 
+		// Effects queued before the target fainted, such as at the end of a turn, do nothing.
+		if self.state.parties()[target_party].active_member_alive(member).is_none()
+		{
+			return BattleExecution::Effect;
+		}
+
 		if self.state.parties_mut()[target_party].active_member_lose_health(member, amount)
 		{
-			let offense_party = user_party;
-			// TODO: This logic is flawed. Player will not gain experience teamed up with AI.
-			if self.state.parties()[offense_party].gain_experience()
+			let offense = MemberIndex
 			{
-				let offense = MemberIndex
-				{
-					party: offense_party,
-					member: user_active,
-				};
-				let defense = MemberIndex
-				{
-					party: target_party,
-					member: target_active,
-				};
-				let experience_map = calculate_experience(self.state.parties(), Some(offense), defense);
+				party: user_party,
+				member: user_active,
+			};
+			let defense = MemberIndex
+			{
+				party: target_party,
+				member: target_active,
+			};
+			let efforts = calculate_efforts(self.state.parties(), MemberIndex
+			{
+				party: target_party,
+				member: target_active,
+			});
+			let experience_map = calculate_experience(self.state.parties(), Some(offense), defense);
 
-				// TODO: Add item/ability modification here.
+			// TODO: Add item/ability modification here.
 
-				for experience_party in &experience_map
+			for experience_party in &experience_map
+			{
+				let party = experience_party.0;
+				// Only parties that gain experience do, regardless of who they are teamed up with.
+				if !self.state.parties()[*party].gain_experience()
 				{
-					let party = experience_party.0;
-					for experience_member in experience_party.1.iter()
+					continue;
+				}
+				for experience_member in experience_party.1.iter()
+				{
+					let member = *experience_member.0;
+					let amount = *experience_member.1;
+					let level = self.state.parties()[*party].member(member).level();
+					let gain = ExperienceGain::new(*party, member, amount, level);
+					self.effects.effect_add_front(Effect::ExperienceGain(gain));
+
+					// Effort values are gained first so that they count towards new levels. Members
+					// that reached the limits gain nothing.
+					let gained = self.state.parties()[*party].member(member).efforts_capped(&efforts);
+					if gained.iter().any(|value| *value != 0)
 					{
-						let member = *experience_member.0;
-						let amount = *experience_member.1;
-						let level = self.state.parties()[*party].member(member).level();
-						let gain = ExperienceGain::new(*party, member, amount, level);
-						self.effects.effect_add_front(Effect::ExperienceGain(gain));
+						self.effects.effect_add_front(Effect::EffortGain(EffortGain
+						{
+							party: *party,
+							member: member,
+							efforts: gained,
+						}));
 					}
 				}
 			}
@@ -412,6 +711,7 @@ impl<'a> BattleRunner<'a>
 					if left == 1
 					{
 						self.sides_alive.remove(&side);
+						self.sides_defeated.push(side);
 					}
 					else
 					{
@@ -432,10 +732,39 @@ impl<'a> BattleRunner<'a>
 		}
 		else
 		{
+			let (ability, held_item) =
+			{
+				let target = self.state.parties()[target_party].active_member(target_active).member;
+				(target.ability(), target.held_item())
+			};
+			ability.after_damage(&mut self.effects, target_party, target_active, &self.state,
+				&mut self.rng);
+			if let Some(held_item) = held_item
+			{
+				held_item.after_damage(&mut self.effects, target_party, target_active, &self.state,
+					&mut self.rng);
+			}
 			BattleExecution::Effect
 		}
 	}
 
+	fn ability_switch_in(&mut self, party: usize, active: usize)
+	{
+		let ability = self.state.parties()[party].active_member(active).member.ability();
+		ability.switch_in(&mut self.effects, party, active, &self.state, &mut self.rng);
+	}
+
+	// Removes conditions that an active member caused on others, such as trapping, before it is
+	// switched out.
+	fn volatile_source_clear(&mut self, party: usize, active: usize)
+	{
+		let reference = self.state.parties()[party].active_member_reference(active);
+		for other in self.state.parties_mut()
+		{
+			other.active_volatile_source_remove(party, reference);
+		}
+	}
+
 	fn apply_effect_retreat(&mut self, party: usize, active: usize) -> BattleExecution
 	{
 		if self.sub_command - 1 < self.replay.sub_command_count(self.command - 1)
@@ -446,8 +775,10 @@ impl<'a> BattleRunner<'a>
 			if let Some(ref target) = *sub_command
 			{
 				let party_index = party;
+				self.volatile_source_clear(party_index, active);
 				self.state.parties_mut()[party_index].switch_active(active, target.target);
 				BattleRunner::expose_party(self.state.parties_mut(), party_index);
+				self.ability_switch_in(party_index, active);
 			}
 
 			BattleExecution::Effect
@@ -475,8 +806,10 @@ impl<'a> BattleRunner<'a>
 			Effect::Switch(ref switch) =>
 			{
 				let party_index = switch.party;
+				self.volatile_source_clear(party_index, switch.member);
 				self.state.parties_mut()[party_index].switch_active(switch.member, switch.target);
 				BattleRunner::expose_party(self.state.parties_mut(), party_index);
+				self.ability_switch_in(party_index, switch.member);
 				BattleExecution::Effect
 			}
 			Effect::Retreat(ref retreat) =>
@@ -493,8 +826,17 @@ impl<'a> BattleRunner<'a>
 			}
 			Effect::ExperienceGain(ref experience_gain) =>
 			{
-				self.state.parties_mut()[experience_gain.party].member_experience_add(experience_gain.member,
-					experience_gain.amount);
+				let level_up = self.state.parties_mut()[experience_gain.party]
+					.member_experience_add(experience_gain.member, experience_gain.amount);
+				if level_up.levels() != 0
+				{
+					self.effects.effect_add_front(Effect::LevelUp(LevelUp
+					{
+						party: experience_gain.party,
+						member: experience_gain.member,
+						level_up: level_up,
+					}));
+				}
 				BattleExecution::Effect
 			}
 			Effect::FlagsChange(ref flags_change) =>
@@ -523,6 +865,105 @@ impl<'a> BattleRunner<'a>
 				}
 				BattleExecution::Effect
 			}
+			Effect::AilmentInflict(ref inflict) =>
+			{
+				let party = &mut self.state.parties_mut()[inflict.party];
+				party.active_member_ailment_set(inflict.active, inflict.ailment, inflict.turns);
+				BattleExecution::Effect
+			}
+			Effect::AilmentCure(ref cure) =>
+			{
+				self.state.parties_mut()[cure.party].active_member_ailment_cure(cure.active);
+				BattleExecution::Effect
+			}
+			Effect::AilmentTick(ref tick) =>
+			{
+				self.state.parties_mut()[tick.party].active_member_ailment_turns_take(tick.active);
+				BattleExecution::Effect
+			}
+			Effect::VolatileAdd(ref volatile_add) =>
+			{
+				let party = &mut self.state.parties_mut()[volatile_add.party];
+				party.active_member_volatile_add(volatile_add.active, volatile_add.volatile.clone());
+				BattleExecution::Effect
+			}
+			Effect::VolatileRemove(ref volatile_remove) =>
+			{
+				let party = &mut self.state.parties_mut()[volatile_remove.party];
+				party.active_member_volatile_remove(volatile_remove.active, volatile_remove.volatile);
+				BattleExecution::Effect
+			}
+			Effect::Heal(ref heal) =>
+			{
+				self.state.parties_mut()[heal.party].active_member_gain_health(heal.active, heal.amount);
+				BattleExecution::Effect
+			}
+			Effect::ItemConsume(ref item_consume) =>
+			{
+				let party = &mut self.state.parties_mut()[item_consume.party];
+				party.active_member_held_item_take(item_consume.active);
+				BattleExecution::Effect
+			}
+			Effect::ItemUse(ref item_use) =>
+			{
+				self.state.parties_mut()[item_use.party].bag_take(item_use.item);
+				BattleExecution::Effect
+			}
+			Effect::HealthRestore(ref restore) =>
+			{
+				let party = &mut self.state.parties_mut()[restore.party];
+				party.member_health_restore(restore.member, restore.amount);
+				BattleExecution::Effect
+			}
+			Effect::AilmentRemove(ref remove) =>
+			{
+				self.state.parties_mut()[remove.party].member_ailment_remove(remove.member);
+				BattleExecution::Effect
+			}
+			Effect::LimitRestore(ref restore) =>
+			{
+				let party = &mut self.state.parties_mut()[restore.party];
+				party.member_limit_restore(restore.member, restore.attack, restore.amount);
+				BattleExecution::Effect
+			}
+			Effect::RecruitShake(_) | Effect::RecruitFailure(_) =>
+			{
+				// Ignore.
+				BattleExecution::Effect
+			}
+			Effect::RecruitSuccess(ref success) =>
+			{
+				let location = self.replay.location();
+				let sub_location = self.replay.sub_location();
+				let date = self.replay.date();
+				let trainer = self.state.parties()[success.party].trainer().cloned();
+				self.recruited = Some(self.state.parties()[success.party].side());
+				let party = &mut self.state.parties_mut()[success.target_party];
+				party.active_member_recruit_set(success.target_active, success.method, trainer,
+					location, sub_location, date);
+				BattleExecution::Effect
+			}
+			Effect::EscapeSuccess(ref success) =>
+			{
+				self.escaped = Some(success.party);
+				BattleExecution::Effect
+			}
+			Effect::EscapeFailure(ref failure) =>
+			{
+				self.state.parties_mut()[failure.party].escape_attempts_add();
+				BattleExecution::Effect
+			}
+			Effect::EffortGain(ref effort_gain) =>
+			{
+				let party = &mut self.state.parties_mut()[effort_gain.party];
+				party.member_efforts_add(effort_gain.member, &effort_gain.efforts);
+				BattleExecution::Effect
+			}
+			Effect::LevelUp(_) =>
+			{
+				// Levels are gained when the experience is.
+				BattleExecution::Effect
+			}
 			Effect::None(_) =>
 			{
 				// Ignore.
@@ -0,0 +1,335 @@
+//! Common functions for saving and loading data in text (TOML) or binary format.
+use std::error;
+use std::fmt;
+use std::io;
+use std::io::{Read, Write};
+
+use num::FromPrimitive;
+use toml;
+
+use base::party::{ExperienceShare, PartyKind};
+use base::target::FieldLayout;
+use gen::attack_list::AttackType;
+use gen::item_list::ItemType;
+use gen::locations::{Location, SubLocation};
+use gen::monster::RecruitMethod;
+use types::attack::AttackId;
+use types::battle::{ExperienceShareId, FieldLayoutId, PartyKindId};
+use types::generic::{LocationId, SubLocationId};
+use types::item::ItemId;
+use types::monster::RecruitMethodId;
+use types::species::{AbilityId, SpeciesId};
+
+/// Represents an error from loading saved data.
+#[derive(Debug)]
+pub enum SerializeError
+{
+	/// The data could not be read or written.
+	IoError(io::Error),
+	/// The text data is not valid TOML.
+	TomlError(String),
+	/// A required value is missing or has an invalid value.
+	InvalidData(String),
+	/// The data was saved with an unsupported format version.
+	VersionMismatch(u32),
+	/// The data was saved with different generated species, attack, ability or item tables.
+	TableMismatch,
+	/// The data refers to a species that no longer exists.
+	InvalidSpecies(SpeciesId),
+	/// The data refers to an attack that no longer exists.
+	InvalidAttack(AttackId),
+	/// The data refers to an ability that no longer exists.
+	InvalidAbility(AbilityId),
+	/// The data refers to an item that no longer exists.
+	InvalidItem(ItemId),
+}
+
+impl fmt::Display for SerializeError
+{
+	fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error>
+	{
+		match *self
+		{
+			SerializeError::IoError(ref e) => write!(f, "IO error: {}", e),
+			SerializeError::TomlError(ref e) => write!(f, "Error parsing TOML: {}", e),
+			SerializeError::InvalidData(ref e) => write!(f, "Invalid data: {}", e),
+			SerializeError::VersionMismatch(version) =>
+			{
+				write!(f, "Unsupported format version {}", version)
+			}
+			SerializeError::TableMismatch =>
+			{
+				write!(f, "Data was saved with different species, attack, ability or item tables")
+			}
+			SerializeError::InvalidSpecies(species) => write!(f, "Unknown species id {}", species),
+			SerializeError::InvalidAttack(attack) => write!(f, "Unknown attack id {}", attack),
+			SerializeError::InvalidAbility(ability) => write!(f, "Unknown ability id {}", ability),
+			SerializeError::InvalidItem(item) => write!(f, "Unknown item id {}", item),
+		}
+	}
+}
+
+impl error::Error for SerializeError
+{
+	fn description(&self) -> &str
+	{
+		match *self
+		{
+			SerializeError::IoError(_) => "IO error",
+			SerializeError::TomlError(_) => "error parsing TOML",
+			SerializeError::InvalidData(_) => "invalid data",
+			SerializeError::VersionMismatch(_) => "unsupported format version",
+			SerializeError::TableMismatch => "different species, attack, ability or item tables",
+			SerializeError::InvalidSpecies(_) => "unknown species",
+			SerializeError::InvalidAttack(_) => "unknown attack",
+			SerializeError::InvalidAbility(_) => "unknown ability",
+			SerializeError::InvalidItem(_) => "unknown item",
+		}
+	}
+}
+
+impl From<io::Error> for SerializeError
+{
+	fn from(f: io::Error) -> Self
+	{
+		SerializeError::IoError(f)
+	}
+}
+
+pub type SerializeResult<T> = Result<T, SerializeError>;
+
+/// Returns an `InvalidData` error for the given key.
+pub fn invalid<T>(key: &str) -> SerializeResult<T>
+{
+	Err(SerializeError::InvalidData(format!("Missing or invalid value `{}`", key)))
+}
+
+/// Converts the given integer into the given type, failing if it does not fit.
+pub fn convert<T: FromPrimitive>(value: i64, key: &str) -> SerializeResult<T>
+{
+	match T::from_i64(value)
+	{
+		Some(value) => Ok(value),
+		None => invalid(key),
+	}
+}
+
+/// Converts a saved attack identifier back into an attack.
+pub fn attack_type_from_id(id: AttackId) -> SerializeResult<AttackType>
+{
+	AttackType::from_u16(id).map_or_else(|| Err(SerializeError::InvalidAttack(id)), Ok)
+}
+
+/// Converts a saved item identifier back into an item.
+pub fn item_type_from_id(id: ItemId) -> SerializeResult<ItemType>
+{
+	ItemType::from_u16(id).map_or_else(|| Err(SerializeError::InvalidItem(id)), Ok)
+}
+
+/// Converts a saved recruit method identifier back into a recruit method.
+pub fn recruit_method_from_id(id: RecruitMethodId) -> SerializeResult<RecruitMethod>
+{
+	RecruitMethod::from_u8(id).map_or_else(|| invalid("recruited"), Ok)
+}
+
+/// Converts a saved location identifier back into a location.
+pub fn location_from_id(id: LocationId) -> SerializeResult<Location>
+{
+	Location::from_u8(id).map_or_else(|| invalid("location"), Ok)
+}
+
+/// Converts a saved sub-location identifier back into a sub-location.
+pub fn sub_location_from_id(id: SubLocationId) -> SerializeResult<SubLocation>
+{
+	SubLocation::from_u8(id).map_or_else(|| invalid("sub_location"), Ok)
+}
+
+/// Converts a saved party kind identifier back into a party kind.
+pub fn party_kind_from_id(id: PartyKindId) -> SerializeResult<PartyKind>
+{
+	match id
+	{
+		0 => Ok(PartyKind::Wild),
+		1 => Ok(PartyKind::Trainer),
+		2 => Ok(PartyKind::Link),
+		_ => invalid("kind"),
+	}
+}
+
+/// Converts a saved experience share identifier back into an experience share.
+pub fn experience_share_from_id(id: ExperienceShareId) -> SerializeResult<ExperienceShare>
+{
+	match id
+	{
+		0 => Ok(ExperienceShare::Exposed),
+		1 => Ok(ExperienceShare::Split),
+		2 => Ok(ExperienceShare::Share),
+		_ => invalid("experience_share"),
+	}
+}
+
+/// Converts a saved field layout identifier back into a field layout.
+pub fn field_layout_from_id(id: FieldLayoutId) -> SerializeResult<FieldLayout>
+{
+	match id
+	{
+		0 => Ok(FieldLayout::Single),
+		1 => Ok(FieldLayout::Double),
+		2 => Ok(FieldLayout::Triple),
+		3 => Ok(FieldLayout::Rotation),
+		_ => invalid("layout"),
+	}
+}
+
+/// Formats a checksum value so it can be stored in TOML without sign issues.
+pub fn checksum_to_string(checksum: u64) -> String
+{
+	format!("{:016x}", checksum)
+}
+
+/// Parses a checksum value created with `checksum_to_string`.
+pub fn checksum_from_str(checksum: &str, key: &str) -> SerializeResult<u64>
+{
+	u64::from_str_radix(checksum, 16).or_else(|_| invalid(key))
+}
+
+/// Parses the entire `input` as a TOML table.
+pub fn toml_read(input: &mut Read) -> SerializeResult<toml::Table>
+{
+	let mut contents = String::new();
+	try!(input.read_to_string(&mut contents));
+
+	let mut parser = toml::Parser::new(&contents);
+	match parser.parse()
+	{
+		Some(table) => Ok(table),
+		None =>
+		{
+			let desc = parser.errors.iter().map(|error|
+			{
+				let (line, col) = parser.to_linecol(error.lo);
+				format!("\"{}\" at line {}, col {}", error.desc, line + 1, col)
+			})
+			.collect::<Vec<_>>().join(", ");
+			Err(SerializeError::TomlError(desc))
+		}
+	}
+}
+
+/// Returns the integer value for the given key converted into the given type.
+pub fn toml_get<T: FromPrimitive>(table: &toml::Table, key: &str) -> SerializeResult<T>
+{
+	match table.get(key).and_then(|value| value.as_integer())
+	{
+		Some(value) => convert(value, key),
+		None => invalid(key),
+	}
+}
+
+/// Returns the boolean value for the given key.
+pub fn toml_get_bool(table: &toml::Table, key: &str) -> SerializeResult<bool>
+{
+	table.get(key).and_then(|value| value.as_bool()).map_or_else(|| invalid(key), Ok)
+}
+
+/// Returns the string value for the given key.
+pub fn toml_get_str<'a>(table: &'a toml::Table, key: &str) -> SerializeResult<&'a str>
+{
+	table.get(key).and_then(|value| value.as_str()).map_or_else(|| invalid(key), Ok)
+}
+
+/// Returns the array value for the given key. Missing arrays are considered empty.
+pub fn toml_get_array<'a>(table: &'a toml::Table, key: &str) -> SerializeResult<&'a [toml::Value]>
+{
+	match table.get(key)
+	{
+		Some(value) => value.as_slice().map_or_else(|| invalid(key), Ok),
+		None => Ok(&[]),
+	}
+}
+
+/// Returns the table value at the given array index.
+pub fn toml_as_table<'a>(value: &'a toml::Value, key: &str) -> SerializeResult<&'a toml::Table>
+{
+	value.as_table().map_or_else(|| invalid(key), Ok)
+}
+
+/// Creates a TOML integer value.
+pub fn toml_int<T: Into<i64>>(value: T) -> toml::Value
+{
+	toml::Value::Integer(value.into())
+}
+
+/// Writes the given value in little-endian order using `size` bytes.
+fn write_bytes(out: &mut Write, value: u64, size: usize) -> io::Result<()>
+{
+	let mut buffer = [0u8; 8];
+	for i in 0..size
+	{
+		buffer[i] = (value >> (i * 8)) as u8;
+	}
+	out.write_all(&buffer[0..size])
+}
+
+/// Reads a little-endian value using `size` bytes.
+fn read_bytes(input: &mut Read, size: usize) -> io::Result<u64>
+{
+	let mut buffer = [0u8; 8];
+	try!(input.read_exact(&mut buffer[0..size]));
+	Ok((0..size).fold(0, |value, i| value | (buffer[i] as u64) << (i * 8)))
+}
+
+pub fn write_u8(out: &mut Write, value: u8) -> io::Result<()>
+{
+	write_bytes(out, value as u64, 1)
+}
+
+pub fn write_u16(out: &mut Write, value: u16) -> io::Result<()>
+{
+	write_bytes(out, value as u64, 2)
+}
+
+pub fn write_u32(out: &mut Write, value: u32) -> io::Result<()>
+{
+	write_bytes(out, value as u64, 4)
+}
+
+pub fn write_u64(out: &mut Write, value: u64) -> io::Result<()>
+{
+	write_bytes(out, value, 8)
+}
+
+/// Writes the length of the given bytes as a `u16` followed by the bytes themselves.
+///
+/// Returns an `InvalidInput` error without writing anything if there are too many bytes for the
+/// length to fit.
+///
+pub fn write_bytes_u16(out: &mut Write, bytes: &[u8]) -> io::Result<()>
+{
+	if bytes.len() > u16::MAX as usize
+	{
+		return Err(io::Error::new(io::ErrorKind::InvalidInput, "too many bytes for a u16 length"));
+	}
+	try!(write_u16(out, bytes.len() as u16));
+	out.write_all(bytes)
+}
+
+pub fn read_u8(input: &mut Read) -> io::Result<u8>
+{
+	read_bytes(input, 1).map(|value| value as u8)
+}
+
+pub fn read_u16(input: &mut Read) -> io::Result<u16>
+{
+	read_bytes(input, 2).map(|value| value as u16)
+}
+
+pub fn read_u32(input: &mut Read) -> io::Result<u32>
+{
+	read_bytes(input, 4).map(|value| value as u32)
+}
+
+pub fn read_u64(input: &mut Read) -> io::Result<u64>
+{
+	read_bytes(input, 8)
+}
@@ -7,6 +7,37 @@ use gen::species::{Growth, Color, Habitat, Group};
 use gen::element::Element;
 use gen::gender::GenderRatio;
 use gen::attack_list::AttackType;
+use gen::ability_list::AbilityType;
+use gen::item_list::ItemType;
+use gen::species_list::SpeciesType;
+
+/// The condition under which a monster evolves into another species.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvolutionTrigger
+{
+	/// Reaching at least the given level.
+	Level(LevelType),
+
+	/// Having the given item used on it.
+	Item(ItemType),
+
+	/// Being traded to another trainer.
+	Trade,
+
+	/// Reaching at least the given friendship.
+	Friendship(FriendshipType),
+}
+
+/// Another species that monsters of a species can evolve into.
+#[derive(Debug)]
+pub struct Evolution
+{
+	/// The species that is evolved into.
+	pub species: SpeciesType,
+
+	/// The condition that must be met to evolve.
+	pub trigger: EvolutionTrigger,
+}
 
 pub struct Species
 {
@@ -94,6 +125,12 @@ pub struct Species
 	/// The yeild speed amount, gained for default a monster of this species, per form.
 	pub yield_speed: &'static [StatBaseType],
 
+	/// The abilities a monster of this species is normally given one of.
+	pub abilities: &'static [AbilityType],
+
+	/// The rare abilities a monster of this species can be given instead of its normal ones.
+	pub abilities_hidden: &'static [AbilityType],
+
 	/// A list of all possible attacks learnable by this monster.
 	pub attacks_list: &'static [AttackType],
 
@@ -121,8 +158,8 @@ pub struct Species
 	///
 	pub attacks_learnable: &'static [(LevelType, &'static [&'static [usize]])],
 
-	// /// Returns the other species that the given monster is capable of evolving into.
-	//pub evolve: fn(&Monster) -> Vec<Id>,
+	/// The other species that monsters of this species are capable of evolving into.
+	pub evolutions: &'static [Evolution],
 }
 
 impl Species
@@ -44,13 +44,13 @@ impl StatModifiers
 	pub fn apply(&mut self, modifiers: &StatModifiers)
 	{
 		self.attack_delta(modifiers.attack);
-		self.defense_delta(modifiers.attack);
-		self.sp_attack_delta(modifiers.attack);
-		self.sp_defense_delta(modifiers.attack);
-		self.speed_delta(modifiers.attack);
-		self.accuracy_delta(modifiers.attack);
-		self.evasion_delta(modifiers.attack);
-		self.critical_delta(modifiers.attack);
+		self.defense_delta(modifiers.defense);
+		self.sp_attack_delta(modifiers.sp_attack);
+		self.sp_defense_delta(modifiers.sp_defense);
+		self.speed_delta(modifiers.speed);
+		self.accuracy_delta(modifiers.accuracy);
+		self.evasion_delta(modifiers.evasion);
+		self.critical_delta(modifiers.critical);
 	}
 	fn base_value(stage: StatModifierType) -> AccuracyType
 	{
@@ -173,9 +173,20 @@ impl StatModifiers
 	}
 	pub fn accuracy_delta(&mut self, delta: StatModifierType)
 	{
-		self.accuracy = clamp::<StatModifierType>(self.speed + delta, StatModifiers::ACCURACY_MIN,
+		self.accuracy = clamp::<StatModifierType>(self.accuracy + delta, StatModifiers::ACCURACY_MIN,
 			StatModifiers::ACCURACY_MAX);
 	}
+	/// The accuracy multiplier when attacking a member with the given modifiers.
+	///
+	/// The evasion stage of the defending member is taken away from the accuracy stage before the
+	/// multiplier is found, so equal stages cancel each other out.
+	///
+	pub fn accuracy_value_against(&self, defending: &StatModifiers) -> AccuracyType
+	{
+		StatModifiers::evasion_accuracy_value(clamp::<StatModifierType>(
+			self.accuracy - defending.evasion, StatModifiers::ACCURACY_MIN,
+			StatModifiers::ACCURACY_MAX))
+	}
 	pub fn evasion_value(&self) -> AccuracyType
 	{
 		StatModifiers::evasion_accuracy_value(self.evasion)
@@ -186,7 +197,7 @@ impl StatModifiers
 	}
 	pub fn evasion_delta(&mut self, delta: StatModifierType)
 	{
-		self.evasion = clamp::<StatModifierType>(self.speed + delta, StatModifiers::EVASION_MIN,
+		self.evasion = clamp::<StatModifierType>(self.evasion + delta, StatModifiers::EVASION_MIN,
 			StatModifiers::EVASION_MAX);
 	}
 	pub fn critical_stage(&self) -> StatModifierType
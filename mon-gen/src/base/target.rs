@@ -0,0 +1,69 @@
+//! Positions of active members on the battle field.
+
+/// How active members are arranged on the field, which decides which members are adjacent.
+///
+/// Each side has a number of positions. Positions are counted from the same end of the field on
+/// every side, so the first position of one side is across from the first position of another.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldLayout
+{
+	/// One member on each side.
+	Single,
+	/// Two members on each side. Every member is adjacent to every other member.
+	Double,
+	/// Three members on each side. Members on the far ends are not adjacent to each other.
+	Triple,
+	/// Three members on each side, but only the member in front is able to reach anyone.
+	Rotation,
+}
+
+impl FieldLayout
+{
+	/// The layout with enough positions for the given number of active members on a side.
+	pub fn with_active(count: usize) -> Self
+	{
+		match count
+		{
+			0 | 1 => FieldLayout::Single,
+			2 => FieldLayout::Double,
+			_ => FieldLayout::Triple,
+		}
+	}
+
+	/// The number of positions on each side.
+	pub fn positions(&self) -> usize
+	{
+		match *self
+		{
+			FieldLayout::Single => 1,
+			FieldLayout::Double => 2,
+			FieldLayout::Triple | FieldLayout::Rotation => 3,
+		}
+	}
+
+	/// The position of a member on a side with `count` active members, given its `index` among
+	/// them.
+	///
+	/// When a side has fewer active members than positions, the remaining members shift towards
+	/// the center. Rotation members stay in place since they rotate to the front instead.
+	///
+	pub fn position(&self, index: usize, count: usize) -> usize
+	{
+		match *self
+		{
+			FieldLayout::Rotation => index,
+			_ => index + self.positions().saturating_sub(count) / 2,
+		}
+	}
+
+	/// Whether the members at positions `from` and `to` are next to or across from each other.
+	pub fn is_adjacent(&self, from: usize, to: usize) -> bool
+	{
+		match *self
+		{
+			FieldLayout::Rotation => from == 0 && to == 0,
+			_ => from <= to + 1 && to <= from + 1,
+		}
+	}
+}
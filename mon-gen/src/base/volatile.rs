@@ -0,0 +1,61 @@
+//! Short-lived conditions of active party members.
+
+/// The kind of a `Volatile` condition, without any of its state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VolatileType
+{
+	Confusion,
+	Flinch,
+	Trap,
+	LeechSeed,
+}
+
+/// A battle condition of an active party member that is removed when it is switched out.
+///
+/// Unlike ailments, volatile conditions are stored by the party and not by the `Monster`, so they
+/// never persist after battle. An active party member can only have one condition of each type.
+///
+/// Conditions caused by another member store the party index of that member and its position in
+/// the party as it was created (see `Party::active_member_reference`). They are also removed when
+/// that member switches out.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Volatile
+{
+	/// Attacks have a chance to hurt the party member instead. Stores the turns left.
+	Confusion(u8),
+	/// The party member is unable to attack for the rest of the turn.
+	Flinch,
+	/// The party member is unable to switch out. Stores the turns left and the trapping member.
+	Trap(u8, usize, usize),
+	/// Health is drained every turn by the member that planted the seed.
+	LeechSeed(usize, usize),
+}
+
+impl Volatile
+{
+	/// The kind of condition.
+	pub fn volatile_type(&self) -> VolatileType
+	{
+		match *self
+		{
+			Volatile::Confusion(_) => VolatileType::Confusion,
+			Volatile::Flinch => VolatileType::Flinch,
+			Volatile::Trap(_, _, _) => VolatileType::Trap,
+			Volatile::LeechSeed(_, _) => VolatileType::LeechSeed,
+		}
+	}
+
+	/// The party index and member reference of the member that caused the condition, if any.
+	pub fn source(&self) -> Option<(usize, usize)>
+	{
+		match *self
+		{
+			Volatile::Trap(_, party, member) | Volatile::LeechSeed(party, member) =>
+			{
+				Some((party, member))
+			}
+			Volatile::Confusion(_) | Volatile::Flinch => None,
+		}
+	}
+}
@@ -0,0 +1,133 @@
+use std::io::Write;
+use std::collections::HashSet;
+
+use build::{CodeGenerateGroup, BuildResult};
+use build::util::{IdResource, Identifiable, write_disclaimer, write_utf8_escaped};
+use types::species::AbilityId;
+
+#[derive(Debug, Deserialize)]
+pub struct Ability
+{
+	name: String,
+	internal: Option<String>,
+	id: AbilityId,
+	description: String,
+	switch_in: Option<String>,
+	before_damage: Option<String>,
+	after_damage: Option<String>,
+	turn_end: Option<String>,
+}
+
+derive_for_id!(Ability, AbilityId);
+
+#[derive(Debug, Deserialize)]
+pub struct AbilityFile
+{
+	pub ability: Ability,
+}
+
+impl CodeGenerateGroup for Ability
+{
+	fn is_valid(group: &HashSet<Ability>) -> BuildResult
+	{
+		IdResource::<AbilityId>::sequential(group)
+	}
+	fn gen_rust_group(group: &HashSet<Ability>, out: &mut Write) -> BuildResult
+	{
+		// The arguments of every hook that adds effects.
+		let data = "effects, party, active, state, rng";
+
+		try!(write_disclaimer(out, "`AbilityMeta`"));
+
+		try!(writeln!(out,
+"use rand::Rng;
+
+use base::ability::AbilityMeta;
+use base::party::PartyMember;
+use base::runner::{{BattleState, BattleEffects}};
+use calculate::ability;
+use gen::element::Element;
+
+/// A trait of `Monster` that takes effect on its own in `Battle`."));
+
+		try!(IdResource::gen_rust_enum(out, "AbilityType", group));
+
+		try!(writeln!(out,
+"impl AbilityType
+{{
+	/// The meta-data for the given ability.
+	pub fn ability(&self) -> &'static AbilityMeta
+	{{
+		&ABILITY_LIST[*self as usize]
+	}}
+
+	/// The effect the ability has when the active member at `party` and `active` is switched in.
+	pub fn switch_in<R: Rng>(&self, effects: &mut BattleEffects, party: usize, active: usize,
+		state: &BattleState, rng: &mut R)
+	{{"));
+		try!(IdResource::gen_rust_hook(out, "AbilityType", group, data, "{}",
+			|ability: &Ability| ability.switch_in.as_ref()));
+
+		try!(writeln!(out,
+"	}}
+
+	/// The damage multiplier for an attack used by `offending`, the holder of the ability.
+	pub fn before_damage(&self, offending: &PartyMember, attack_index: usize,
+		defending: &PartyMember) -> f32
+	{{"));
+		try!(IdResource::gen_rust_hook(out, "AbilityType", group,
+			"offending, attack_index, defending", "1f32",
+			|ability: &Ability| ability.before_damage.as_ref()));
+
+		try!(writeln!(out,
+"	}}
+
+	/// The effect the ability has after the active member at `party` and `active` took damage and
+	/// is still alive.
+	pub fn after_damage<R: Rng>(&self, effects: &mut BattleEffects, party: usize, active: usize,
+		state: &BattleState, rng: &mut R)
+	{{"));
+		try!(IdResource::gen_rust_hook(out, "AbilityType", group, data, "{}",
+			|ability: &Ability| ability.after_damage.as_ref()));
+
+		try!(writeln!(out,
+"	}}
+
+	/// The effect the ability has at the end of every turn for the active member at `party` and
+	/// `active`.
+	pub fn turn_end<R: Rng>(&self, effects: &mut BattleEffects, party: usize, active: usize,
+		state: &BattleState, rng: &mut R)
+	{{"));
+		try!(IdResource::gen_rust_hook(out, "AbilityType", group, data, "{}",
+			|ability: &Ability| ability.turn_end.as_ref()));
+
+		try!(writeln!(out,
+"	}}
+}}
+
+const ABILITY_LIST: &'static [AbilityMeta] = &["));
+
+		for id in 0 as AbilityId..group.len() as AbilityId
+		{
+			let ability = group.get::<AbilityId>(&id).unwrap();
+			try!(writeln!(out, "\tAbilityMeta\n\t{{"));
+
+			try!(write!(out, "\t\tname: "));
+			try!(write_utf8_escaped(out, &ability.name));
+			try!(writeln!(out, ","));
+
+			try!(write!(out, "\t\tdescription: "));
+			try!(write_utf8_escaped(out, &ability.description));
+			try!(writeln!(out, ","));
+
+			try!(writeln!(out, "\t}},"));
+		}
+
+		try!(writeln!(out, "];"));
+		Ok(())
+	}
+	fn gen_constants_group(group: &HashSet<Ability>, out: &mut Write) -> BuildResult
+	{
+		IdResource::gen_constants(out, "ABILITY", group)
+	}
+}
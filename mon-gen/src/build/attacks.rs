@@ -3,7 +3,8 @@ use std::collections::HashSet;
 
 use build::{CodeGenerate, CodeGenerateGroup, BuildResult, Error};
 use build::util::{IdNamePairSet, IdResource, Identifiable, write_disclaimer, write_utf8_escaped};
-use types::attack::{AttackId, PowerType, AccuracyType, LimitType, PriorityType, CategoryId};
+use types::attack::{AttackId, PowerType, AccuracyType, LimitType, PriorityType, CategoryId,
+	AilmentId};
 
 fn default_side() -> String
 {
@@ -53,8 +54,7 @@ pub struct Attack
 	category: String,
 	#[serde(default)]
 	power: PowerType,
-	#[serde(default)]
-	accuracy: AccuracyType,
+	accuracy: Option<AccuracyType>,
 	limit: LimitType,
 	#[serde(default)]
 	priority: PriorityType,
@@ -89,6 +89,15 @@ impl CodeGenerateGroup for Attack
 				return Err(Error::SyntaxError(format!("Invalid attribute 'range' for attack '{}'",
 					attack.name)));
 			}
+			if let Some(accuracy) = attack.accuracy
+			{
+				// Attacks that never miss omit their accuracy instead.
+				if accuracy <= 0.0 || accuracy > 1.0
+				{
+					return Err(Error::SyntaxError(format!(
+						"Invalid attribute 'accuracy' for attack '{}'", attack.name)));
+				}
+			}
 		}
 		IdResource::<AttackId>::sequential(group)
 	}
@@ -102,12 +111,14 @@ impl CodeGenerateGroup for Attack
 use base::attack::{{AttackMeta, Target}};
 use base::command::CommandAttack;
 use base::runner::{{BattleFlags, BattleState, BattleEffects}};
+use calculate::ailment;
 use calculate::common::*;
 use calculate::effects::*;
 use calculate::lingering;
 use calculate::lingering::LingeringType;
 use calculate::modifier;
-use gen::attack::Category;
+use calculate::volatile;
+use gen::attack::{{Ailment, Category}};
 use gen::element::Element;
 use types::attack::AccuracyType;
 
@@ -176,7 +187,14 @@ const ATTACK_LIST: &'static [AttackMeta] = &["));
 			try!(writeln!(out, "\t\telement: Element::{},", attack.element));
 			try!(writeln!(out, "\t\tcategory: Category::{},", attack.category));
 			try!(writeln!(out, "\t\tpower: {},", attack.power));
-			try!(writeln!(out, "\t\taccuracy: {} as AccuracyType,", attack.accuracy));
+			match attack.accuracy
+			{
+				Some(accuracy) =>
+				{
+					try!(writeln!(out, "\t\taccuracy: Some({} as AccuracyType),", accuracy));
+				}
+				None => try!(writeln!(out, "\t\taccuracy: None,")),
+			}
 			try!(writeln!(out, "\t\tlimit: {},", attack.limit));
 			try!(writeln!(out, "\t\tpriority: {:?},", attack.priority));
 
@@ -205,25 +223,35 @@ const ATTACK_LIST: &'static [AttackMeta] = &["));
 	}
 }
 
+#[derive(Debug, Deserialize)]
+pub struct AilmentClassifiers
+{
+	nonvolatile: IdNamePairSet<AilmentId>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct AttackClassifiers
 {
 	categories: IdNamePairSet<CategoryId>,
+	ailments: AilmentClassifiers,
 }
 
 impl CodeGenerate for AttackClassifiers
 {
 	fn is_valid(&self) -> BuildResult
 	{
-		IdResource::sequential(&self.categories)
+		try!(IdResource::sequential(&self.categories));
+		IdResource::sequential(&self.ailments.nonvolatile)
 	}
 	fn gen_rust(&self, out: &mut Write) -> BuildResult
 	{
 		try!(write_disclaimer(out, "attack classifiers"));
-		IdResource::gen_rust_enum(out, "Category", &self.categories)
+		try!(IdResource::gen_rust_enum(out, "Category", &self.categories));
+		IdResource::gen_rust_enum(out, "Ailment", &self.ailments.nonvolatile)
 	}
 	fn gen_constants(&self, out: &mut Write) -> BuildResult
 	{
-		IdResource::gen_constants(out, "ATTACK_CATEGORY", &self.categories)
+		try!(IdResource::gen_constants(out, "ATTACK_CATEGORY", &self.categories));
+		IdResource::gen_constants(out, "ATTACK_AILMENT", &self.ailments.nonvolatile)
 	}
 }
@@ -68,6 +68,64 @@ impl {}
 			try!(writeln!(out, "]).unwrap().clone(),"));
 		}
 
+		try!(write!(out, "\t\t}}
+	}}
+
+	/// The {} that the given personality has with the given ratio.
+	///
+	/// The lowest byte of the personality is scaled to the ratio, so lower values are given the
+	/// earlier {}s listed in the ratio.
+	///
+	pub fn with_personality(personality: PersonalityType, ratio: {}) -> Self
+	{{
+		let value = (personality & 0xFF) as usize;
+		match ratio
+		{{\n", of_name.to_lowercase(), of_name.to_lowercase(), name));
+
+		for id in ids
+		{
+			try!(write!(out, "\t\t\t{}::{} => {{ let genders = [", name,
+				Identifiable::identifier(id)));
+			let ratios = try!(id.get_ratio().ok_or(Error::SyntaxError(
+				"Invalid ratio syntax".to_string())));
+			for (index, value) in ratios.iter().enumerate()
+			{
+				for _ in 0..*value
+				{
+					let i = IdType::from(index).unwrap();
+					try!(write!(out, "{}::{},", of_name,
+						of_ids.get::<IdType>(&i).unwrap().name()));
+				}
+			}
+			try!(writeln!(out, "]; genders[value * genders.len() / 256] }}"));
+		}
+
+		try!(write!(out, "\t\t}}
+	}}
+
+	/// Whether monsters with the given ratio can have this {}.
+	pub fn possible(&self, ratio: {}) -> bool
+	{{
+		match ratio
+		{{\n", of_name.to_lowercase(), name));
+
+		for id in ids
+		{
+			try!(write!(out, "\t\t\t{}::{} => [", name, Identifiable::identifier(id)));
+			let ratios = try!(id.get_ratio().ok_or(Error::SyntaxError(
+				"Invalid ratio syntax".to_string())));
+			for (index, value) in ratios.iter().enumerate()
+			{
+				if *value != 0
+				{
+					let i = IdType::from(index).unwrap();
+					try!(write!(out, "{}::{}, ", of_name,
+						of_ids.get::<IdType>(&i).unwrap().name()));
+				}
+			}
+			try!(writeln!(out, "].contains(self),"));
+		}
+
 		try!(writeln!(out, "\t\t}}\n\t}}\n}}"));
 		Ok(())
 	}
@@ -93,7 +151,8 @@ impl CodeGenerate for GenderClassifiers
 	fn gen_rust(&self, out: &mut Write) -> BuildResult
 	{
 		try!(write_disclaimer(out, "genders classifiers"));
-		try!(writeln!(out, "use rand::Rng;\n"));
+		try!(writeln!(out, "use rand::Rng;"));
+		try!(writeln!(out, "use types::monster::PersonalityType;\n"));
 		try!(IdResource::gen_rust_enum(out, "Gender", &self.genders));
 		try!(IdResource::gen_rust_enum(out, "GenderRatio", &self.gender_ratios));
 		IdNamePairRatio::gen_rust_ratios(out, "GenderRatio", &self.gender_ratios, "Gender",
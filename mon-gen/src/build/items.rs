@@ -0,0 +1,146 @@
+use std::io::Write;
+use std::collections::HashSet;
+
+use build::{CodeGenerateGroup, BuildResult};
+use build::util::{IdResource, Identifiable, write_disclaimer, write_utf8_escaped};
+use types::item::ItemId;
+
+#[derive(Debug, Deserialize)]
+pub struct Item
+{
+	name: String,
+	internal: Option<String>,
+	id: ItemId,
+	description: String,
+	before_damage: Option<String>,
+	after_damage: Option<String>,
+	turn_end: Option<String>,
+	#[serde(rename = "use")]
+	use_effect: Option<String>,
+}
+
+derive_for_id!(Item, ItemId);
+
+#[derive(Debug, Deserialize)]
+pub struct ItemFile
+{
+	pub item: Item,
+}
+
+impl CodeGenerateGroup for Item
+{
+	fn is_valid(group: &HashSet<Item>) -> BuildResult
+	{
+		IdResource::<ItemId>::sequential(group)
+	}
+	fn gen_rust_group(group: &HashSet<Item>, out: &mut Write) -> BuildResult
+	{
+		// The arguments of every hook that adds effects.
+		let data = "effects, party, active, state, rng";
+
+		try!(write_disclaimer(out, "`ItemMeta`"));
+
+		try!(writeln!(out,
+"use rand::Rng;
+
+use base::command::CommandItem;
+use base::item::ItemMeta;
+use base::party::PartyMember;
+use base::runner::{{BattleState, BattleEffects}};
+use calculate::item;
+use gen::attack::Ailment;
+use gen::element::Element;
+
+/// An object held by `Monster` or used from the bag in `Battle`."));
+
+		try!(IdResource::gen_rust_enum(out, "ItemType", group));
+
+		try!(writeln!(out,
+"impl ItemType
+{{
+	/// The meta-data for the given item.
+	pub fn item(&self) -> &'static ItemMeta
+	{{
+		&ITEM_LIST[*self as usize]
+	}}
+
+	/// The damage multiplier for an attack used by `offending`, the holder of the item.
+	pub fn before_damage(&self, offending: &PartyMember, attack_index: usize,
+		defending: &PartyMember) -> f32
+	{{"));
+		try!(IdResource::gen_rust_hook(out, "ItemType", group,
+			"offending, attack_index, defending", "1f32",
+			|item: &Item| item.before_damage.as_ref()));
+
+		try!(writeln!(out,
+"	}}
+
+	/// The effect the item has after the active member at `party` and `active` took damage and is
+	/// still alive.
+	pub fn after_damage<R: Rng>(&self, effects: &mut BattleEffects, party: usize, active: usize,
+		state: &BattleState, rng: &mut R)
+	{{"));
+		try!(IdResource::gen_rust_hook(out, "ItemType", group, data, "{}",
+			|item: &Item| item.after_damage.as_ref()));
+
+		try!(writeln!(out,
+"	}}
+
+	/// The effect the item has at the end of every turn for the active member at `party` and
+	/// `active`.
+	pub fn turn_end<R: Rng>(&self, effects: &mut BattleEffects, party: usize, active: usize,
+		state: &BattleState, rng: &mut R)
+	{{"));
+		try!(IdResource::gen_rust_hook(out, "ItemType", group, data, "{}",
+			|item: &Item| item.turn_end.as_ref()));
+
+		try!(writeln!(out,
+"	}}
+
+	/// Whether the item can be used from the bag during battle.
+	pub fn usable(&self) -> bool
+	{{"));
+		let usable = "true".to_string();
+		try!(IdResource::gen_rust_hook(out, "ItemType", group, "", "false",
+			|item| item.use_effect.as_ref().map(|_| &usable)));
+
+		try!(writeln!(out,
+"	}}
+
+	/// The effect of using the item from the bag as described by `command`.
+	pub fn use_effects<R: Rng>(&self, effects: &mut BattleEffects, command: &CommandItem,
+		state: &BattleState, rng: &mut R)
+	{{"));
+		try!(IdResource::gen_rust_hook(out, "ItemType", group, "effects, command, state, rng",
+			"{}", |item: &Item| item.use_effect.as_ref()));
+
+		try!(writeln!(out,
+"	}}
+}}
+
+const ITEM_LIST: &'static [ItemMeta] = &["));
+
+		for id in 0 as ItemId..group.len() as ItemId
+		{
+			let item = group.get::<ItemId>(&id).unwrap();
+			try!(writeln!(out, "\tItemMeta\n\t{{"));
+
+			try!(write!(out, "\t\tname: "));
+			try!(write_utf8_escaped(out, &item.name));
+			try!(writeln!(out, ","));
+
+			try!(write!(out, "\t\tdescription: "));
+			try!(write_utf8_escaped(out, &item.description));
+			try!(writeln!(out, ","));
+
+			try!(writeln!(out, "\t}},"));
+		}
+
+		try!(writeln!(out, "];"));
+		Ok(())
+	}
+	fn gen_constants_group(group: &HashSet<Item>, out: &mut Write) -> BuildResult
+	{
+		IdResource::gen_constants(out, "ITEM", group)
+	}
+}
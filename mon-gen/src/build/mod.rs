@@ -7,6 +7,8 @@ mod species;
 mod locations;
 mod monster;
 mod attacks;
+mod abilities;
+mod items;
 
 use std::default::Default;
 use std::path::Path;
@@ -20,13 +22,15 @@ use toml;
 use serde;
 use filetime::FileTime;
 
-use build::util::Identifiable;
+use build::util::{Identifiable, CHECKSUM_INIT, checksum, write_checksum};
 use build::elements::ElementFile;
 use build::gender::GenderClassifiers;
 use build::locations::LocationClassifiers;
 use build::monster::MonsterClassifiers;
 use build::species::{SpeciesFile, Species, SpeciesClassifiers};
 use build::attacks::{AttackFile, Attack, AttackClassifiers};
+use build::abilities::{AbilityFile, Ability};
+use build::items::{ItemFile, Item};
 
 /// Represents a detailed TOML parser error.
 #[derive(Debug)]
@@ -203,6 +207,8 @@ struct BuildTimes
 	classifiers: ClassifierBuildTimes,
 	species: u64,
 	attacks: u64,
+	abilities: u64,
+	items: u64,
 }
 
 fn file_append_to_write(from: &mut File, to: &mut Write) -> io::Result<()>
@@ -303,13 +309,26 @@ pub fn build<P1, P2, P3>(build_cache_dir: P1, input_dir: P2, output_dir: P3, reb
 		.open(build_cache_dir.as_ref().join("constants_species_list.rs")));
 	failure = failure || !build_code_dir::<SpeciesFile, _, _, _, Species>(
 		input_dir.as_ref().join("species"), output_dir.as_ref().join("species_list.rs"),
-		&mut times.species, rebuild, &mut constants_species_list, &mut |file| file.species);
+		&mut times.species, rebuild, &mut constants_species_list, "SPECIES_TABLE_CHECKSUM",
+		&mut |file| file.species);
 	let mut constants_attack_list = try!(OpenOptions::new().read(true).write(true).create(true)
 		.open(build_cache_dir.as_ref().join("constants_attack_list.rs")));
 	failure = failure || !build_code_dir::<AttackFile, _, _, _, Attack>(
 		input_dir.as_ref().join("attacks"), output_dir.as_ref().join("attack_list.rs"),
-		&mut times.attacks, rebuild, &mut constants_attack_list, &mut |file| file.attack);
-
+		&mut times.attacks, rebuild, &mut constants_attack_list, "ATTACK_TABLE_CHECKSUM",
+		&mut |file| file.attack);
+	let mut constants_ability_list = try!(OpenOptions::new().read(true).write(true).create(true)
+		.open(build_cache_dir.as_ref().join("constants_ability_list.rs")));
+	failure = failure || !build_code_dir::<AbilityFile, _, _, _, Ability>(
+		input_dir.as_ref().join("abilities"), output_dir.as_ref().join("ability_list.rs"),
+		&mut times.abilities, rebuild, &mut constants_ability_list, "ABILITY_TABLE_CHECKSUM",
+		&mut |file| file.ability);
+	let mut constants_item_list = try!(OpenOptions::new().read(true).write(true).create(true)
+		.open(build_cache_dir.as_ref().join("constants_item_list.rs")));
+	failure = failure || !build_code_dir::<ItemFile, _, _, _, Item>(
+		input_dir.as_ref().join("items"), output_dir.as_ref().join("item_list.rs"),
+		&mut times.items, rebuild, &mut constants_item_list, "ITEM_TABLE_CHECKSUM",
+		&mut |file| file.item);
 
 	let mut file = try!(File::create(build_file));
 	try!(file.write_all(&toml::encode_str(&times).as_bytes()));
@@ -325,6 +344,8 @@ pub fn build<P1, P2, P3>(build_cache_dir: P1, input_dir: P2, output_dir: P3, reb
 		try!(file_append_to_write(&mut constants_battle, &mut constants));
 		try!(file_append_to_write(&mut constants_species, &mut constants));
 		try!(file_append_to_write(&mut constants_species_list, &mut constants));
+		try!(file_append_to_write(&mut constants_ability_list, &mut constants));
+		try!(file_append_to_write(&mut constants_item_list, &mut constants));
 	}
 
 	Ok(!failure)
@@ -350,7 +371,7 @@ fn build_code<T, P1, P2>(input_path: P1, output_dir: P2, build_times: &mut u64,
 }
 
 fn build_code_dir<T, P1, P2, F, U>(input_path: P1, output_dir: P2, build_times: &mut u64,
-	rebuild: bool, output_constants: &mut Write, convert_func: &mut F) -> bool
+	rebuild: bool, output_constants: &mut Write, checksum_name: &str, convert_func: &mut F) -> bool
 	where T: serde::Deserialize, U: 'static + CodeGenerateGroup + Eq + Hash + Identifiable,
 		P1: AsRef<Path>, P2: AsRef<Path>, F: FnMut(T) -> U
 {
@@ -358,12 +379,13 @@ fn build_code_dir<T, P1, P2, F, U>(input_path: P1, output_dir: P2, build_times:
 	{
 		print!("Building directory `{:?}`... ",input_path.as_ref());
 		build_dir_from_time(&input_path, build_times, rebuild, convert_func,
-			&mut |t: &HashSet<U>|
+			&mut |t: &HashSet<U>, resource_checksum: u64|
 		{
 			print!("WRITING... ");
 			let mut output_rust = try!(File::create(&output_dir));
 			try!(U::is_valid(t));
 			try!(U::gen_rust_group(t, &mut output_rust));
+			try!(write_checksum(&mut output_rust, checksum_name, resource_checksum));
 			try!(U::gen_constants_group(t, output_constants));
 			Ok(())
 		})
@@ -399,8 +421,12 @@ pub fn parse_toml<T: serde::Deserialize>(file: &mut File, name: &String) -> Resu
 {
 	let mut contents = String::new();
 	try!(file.read_to_string(&mut contents));
+	parse_toml_str(&contents, name)
+}
 
-	let mut parser = toml::Parser::new(&contents);
+pub fn parse_toml_str<T: serde::Deserialize>(contents: &str, name: &String) -> Result<T, Error>
+{
+	let mut parser = toml::Parser::new(contents);
 	let toml = try!(parser.parse().ok_or(TomlParserError::from_parser(&parser, name)));
 
 	toml::decode::<T>(toml::Value::Table(toml)).ok_or(Error::from(TomlParserError::from_parser(
@@ -433,7 +459,7 @@ fn build_from_time<T, P, F>(path: P, build_time: &mut u64, rebuild: bool, closur
 fn build_dir_from_time<T, P, F, F2, U>(path: P, build_time: &mut u64, rebuild: bool,
 	convert_func: &mut F2, closure: &mut F) -> ProcessedResult
 	where T: serde::Deserialize, U: 'static + CodeGenerateGroup + Eq + Hash + Identifiable, P: AsRef<Path>,
-		F: FnMut(&HashSet<U>) -> BuildResult, F2: FnMut(T) -> U
+		F: FnMut(&HashSet<U>, u64) -> BuildResult, F2: FnMut(T) -> U
 {
 	let dir = try!(read_dir(&path));
 	let (lower_bound_size, _) = dir.size_hint();
@@ -444,6 +470,8 @@ fn build_dir_from_time<T, P, F, F2, U>(path: P, build_time: &mut u64, rebuild: b
 
 	if rebuild || *build_time != time
 	{
+		// Contents of each file, sorted by file name so that directory order does not matter.
+		let mut resources = Vec::with_capacity(lower_bound_size);
 		for path in dir
 		{
 			let filepath = try!(path);
@@ -451,7 +479,10 @@ fn build_dir_from_time<T, P, F, F2, U>(path: P, build_time: &mut u64, rebuild: b
 			let name = try!(filepath.file_name().into_string().map_err(|_| Error::SyntaxError(
 				"Unable to read OS file metadata".to_string())));
 
-			let toml_contents = parse_toml::<T>(&mut file, &name);
+			let mut contents = String::new();
+			try!(file.read_to_string(&mut contents));
+			let toml_contents = parse_toml_str::<T>(&contents, &name);
+			resources.push((name, contents));
 			match toml_contents
 			{
 				Ok(contents) =>
@@ -475,7 +506,12 @@ fn build_dir_from_time<T, P, F, F2, U>(path: P, build_time: &mut u64, rebuild: b
 				}
 			}
 		}
-		try!(closure(&set));
+		resources.sort();
+		let resource_checksum = resources.iter().fold(CHECKSUM_INIT, |hash, resource|
+		{
+			checksum(checksum(hash, resource.0.as_bytes()), resource.1.as_bytes())
+		});
+		try!(closure(&set, resource_checksum));
 
 		*build_time = time;
 		Ok(true)
@@ -3,14 +3,29 @@ use std::io::Write;
 
 use build::{BuildResult, CodeGenerate};
 use build::util::{IdNamePairSet, IdResource, Identifiable, write_disclaimer};
+use types::monster::RecruitMethodId;
 use types::species::{GrowthId};
 
+use std::collections::HashSet;
+
+/// A method of recruiting with its multiplier for the chance of success.
+#[derive(Debug, Deserialize)]
+pub struct RecruitMethodClassifier
+{
+	id: RecruitMethodId,
+	name: String,
+	internal: Option<String>,
+	rate: Option<f32>,
+}
+
+derive_for_id!(RecruitMethodClassifier, RecruitMethodId);
+
 #[derive(Debug, Deserialize)]
 pub struct MonsterClassifiers
 {
 	natures: IdNamePairSet<GrowthId>,
 	#[serde(rename = "recruit-methods")]
-	recruit_methods: IdNamePairSet<GrowthId>,
+	recruit_methods: HashSet<RecruitMethodClassifier>,
 }
 
 impl CodeGenerate for MonsterClassifiers
@@ -31,12 +46,32 @@ impl CodeGenerate for MonsterClassifiers
 	fn rand<R: rand::Rng>(rng: &mut R) -> Self
 	{{
 		*rng.choose(&["));
-		for nature in &self.natures
+		// Natures are listed in order so that the same random numbers choose the same nature.
+		for id in 0..self.natures.len() as GrowthId
 		{
+			let nature = self.natures.get::<GrowthId>(&id).unwrap();
 			try!(writeln!(out, "\t\t\tNature::{},", nature.name()))
 		}
 		try!(writeln!(out, "\t\t]).unwrap()\n\t}}\n}}"));
-		IdResource::gen_rust_enum(out, "RecruitMethod", &self.recruit_methods)
+		try!(IdResource::gen_rust_enum(out, "RecruitMethod", &self.recruit_methods));
+		try!(writeln!(out,
+"impl RecruitMethod
+{{
+	/// The multiplier for the chance of recruiting using this method.
+	pub fn rate(&self) -> f32
+	{{
+		match *self
+		{{"));
+		for method in &self.recruit_methods
+		{
+			if let Some(rate) = method.rate
+			{
+				try!(writeln!(out, "\t\t\tRecruitMethod::{} => {:?},",
+					Identifiable::identifier(method), rate));
+			}
+		}
+		try!(writeln!(out, "\t\t\t_ => 1f32,\n\t\t}}\n\t}}\n}}"));
+		Ok(())
 	}
 	fn gen_constants(&self, out: &mut Write) -> BuildResult
 	{
@@ -206,14 +206,50 @@ impl<T> SpeciesFormChange<T> where T: Clone + CustomDisplay
 #[derive(Debug, Deserialize)]
 pub struct SpeciesEvolution
 {
-	level: LevelType,
+	level: Option<LevelType>,
+	item: Option<String>,
+	#[serde(default)]
+	trade: bool,
+	friendship: Option<FriendshipType>,
 	species: Vec<String>,
 }
 
+impl SpeciesEvolution
+{
+	// Returns the generated trigger, which fails unless exactly one trigger is given.
+	fn trigger(&self, species: &String) -> Result<String, Error>
+	{
+		let mut triggers = Vec::new();
+		if let Some(level) = self.level
+		{
+			triggers.push(format!("Level({})", level));
+		}
+		if let Some(ref item) = self.item
+		{
+			triggers.push(format!("Item(ItemType::{})", item));
+		}
+		if self.trade
+		{
+			triggers.push("Trade".to_string());
+		}
+		if let Some(friendship) = self.friendship
+		{
+			triggers.push(format!("Friendship({})", friendship));
+		}
+		if triggers.len() != 1
+		{
+			return Err(Error::SyntaxError(format!("Invalid evolution for species `{}`. Must have \
+				exactly 1 trigger.", species)));
+		}
+		Ok(triggers.pop().unwrap())
+	}
+}
+
 #[derive(Debug, Deserialize)]
 pub struct SpeciesAbilities
 {
 	default: Vec<String>,
+	#[serde(default)]
 	hidden: Vec<String>,
 }
 
@@ -453,6 +489,11 @@ impl CodeGenerateGroup for Species
 {
 	fn is_valid(group: &HashSet<Species>) -> BuildResult
 	{
+		let identifiers: HashSet<&String> = group.iter().map(|species|
+		{
+			Identifiable::identifier(species)
+		}).collect();
+
 		for species in group
 		{
 			let mut valid = false;
@@ -469,6 +510,23 @@ impl CodeGenerateGroup for Species
 				return Err(Error::SyntaxError(format!("Invalid learnable attacks for species `{}`.
 					Must have an attack learnable at level 1.", species.name).to_string()));
 			}
+			if species.abilities.default.is_empty()
+			{
+				return Err(Error::SyntaxError(format!("Invalid abilities for species `{}`. Must \
+					have at least 1 default ability.", species.name)));
+			}
+			for evolution in &species.evolutions
+			{
+				try!(evolution.trigger(&species.name));
+				for evolved in &evolution.species
+				{
+					if !identifiers.contains(evolved)
+					{
+						return Err(Error::SyntaxError(format!("Invalid evolution for species \
+							`{}`. Species `{}` does not exist.", species.name, evolved)));
+					}
+				}
+			}
 		}
 		IdResource::<SpeciesId>::sequential(group)
 	}
@@ -476,12 +534,14 @@ impl CodeGenerateGroup for Species
 	{
 		try!(write_disclaimer(out, "static species data"));
 		try!(writeln!(out,
-"use base::species::Species;
+"use base::species::{{Evolution, EvolutionTrigger, Species}};
 use types::species::{{SpeciesId, MetricType}};
 use gen::element::Element;
 use gen::gender::GenderRatio;
 use gen::species::{{Growth, Color, Habitat, Group}};
 use gen::attack_list::AttackType;
+use gen::ability_list::AbilityType;
+use gen::item_list::ItemType;
 "));
 
 		try!(IdResource::<SpeciesId>::gen_rust_enum(out, "SpeciesType", group));
@@ -640,6 +700,20 @@ const SPECIES_LIST: &'static [Species] = &["));
 				&species.forms, &yield_stat_list, Statistic::Speed));
 			try!(writeln!(out, "],"));
 
+			try!(write!(out, "\t\tabilities: &["));
+			for ability in &species.abilities.default
+			{
+				try!(write!(out, "AbilityType::{}, ", ability));
+			}
+			try!(writeln!(out, "],"));
+
+			try!(write!(out, "\t\tabilities_hidden: &["));
+			for ability in &species.abilities.hidden
+			{
+				try!(write!(out, "AbilityType::{}, ", ability));
+			}
+			try!(writeln!(out, "],"));
+
 			let mut attack_map = IndexMap::new();
 
 			try!(write!(out, "\t\tattacks_learnable: &["));
@@ -748,6 +822,18 @@ const SPECIES_LIST: &'static [Species] = &["));
 			}
 			try!(writeln!(out, "],"));
 
+			try!(write!(out, "\t\tevolutions: &["));
+			for evolution in &species.evolutions
+			{
+				let trigger = try!(evolution.trigger(&species.name));
+				for evolved in &evolution.species
+				{
+					try!(write!(out, "Evolution {{ species: SpeciesType::{}, trigger: \
+						EvolutionTrigger::{} }}, ", evolved, trigger));
+				}
+			}
+			try!(writeln!(out, "],"));
+
 			try!(writeln!(out, "\t}},"));
 		}
 
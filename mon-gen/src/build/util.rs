@@ -102,6 +102,30 @@ pub fn write_indent(out: &mut Write, mut level: usize) -> io::Result<()>
 	Ok(())
 }
 
+/// The initial value for `checksum`.
+pub const CHECKSUM_INIT: u64 = 0xcbf29ce484222325;
+
+/// Continues the 64-bit FNV-1a hash `hash` with the given `bytes`.
+pub fn checksum(mut hash: u64, bytes: &[u8]) -> u64
+{
+	for byte in bytes
+	{
+		hash ^= *byte as u64;
+		hash = hash.wrapping_mul(0x100000001b3);
+	}
+	hash
+}
+
+/// Prints a Rust style constant containing the checksum of the resources used to generate code.
+pub fn write_checksum(out: &mut Write, name: &str, checksum: u64) -> io::Result<()>
+{
+	try!(writeln!(out, "/// Checksum of the resources used to generate this file."));
+	try!(writeln!(out, "///"));
+	try!(writeln!(out, "/// Saved data that refers to identifiers from this file is only valid if the checksum matches."));
+	try!(writeln!(out, "///"));
+	writeln!(out, "pub const {}: u64 = {:#x};", name, checksum)
+}
+
 /// Prints a Rust style byte literal escaped version of the given string `s`.
 pub fn write_utf8_escaped(out: &mut Write, s: &str) -> io::Result<()>
 {
@@ -163,6 +187,29 @@ impl<IdType: Numeric> IdResource<IdType>
 		}
 		Ok(())
 	}
+	/// Generates the body of a method matching on the enum `name` for resources with a hook.
+	///
+	/// Each hook returned by `hook` is inserted with `data` replaced by the method arguments.
+	/// Resources without the hook use `default` instead.
+	///
+	pub fn gen_rust_hook<'a, T, F>(out: &mut Write, name: &str, ids: &'a HashSet<T>,
+		data: &str, default: &str, hook: F) -> BuildResult
+			where T: 'static + IdResource<IdType> + Borrow<IdType> + Identifiable + Hash + Eq,
+				F: Fn(&'a T) -> Option<&'a String>
+	{
+		try!(writeln!(out, "\t\tmatch *self\n\t\t{{"));
+		for i in range(IdType::zero(), IdType::from_usize(ids.len()).unwrap())
+		{
+			let resource = ids.get(&i).unwrap();
+			if let Some(effect) = hook(resource)
+			{
+				try!(writeln!(out, "\t\t\t{}::{} => {},", name, Identifiable::identifier(resource),
+					effect.replace("data", data)));
+			}
+		}
+		try!(writeln!(out, "\t\t\t_ => {},\n\t\t}}", default));
+		Ok(())
+	}
 	pub fn gen_rust_enum<T>(out: &mut Write, name: &str, ids: &std::collections::HashSet<T>)
 		-> BuildResult where T: 'static + IdResource<IdType> + Identifiable + Hash + Eq
 	{
@@ -172,16 +219,14 @@ impl<IdType: Numeric> IdResource<IdType>
 		ids: &std::collections::HashSet<T>, mut indent: usize) -> BuildResult
 			where T: 'static + IdResource<IdType> + Identifiable + Hash + Eq
 	{
-		if cfg!(feature = "c_api")
-		{
-			try!(write_indent(out, indent));
-			try!(writeln!(out, "enum_from_primitive!"));
-			try!(write_indent(out, indent));
-			try!(writeln!(out, "{{"));
-			indent += 1;
-			try!(write_indent(out, indent));
-			try!(writeln!(out, "#[allow(dead_code)]"));
-		}
+		// Identifiers are converted back into enums when loading saved data.
+		try!(write_indent(out, indent));
+		try!(writeln!(out, "enum_from_primitive!"));
+		try!(write_indent(out, indent));
+		try!(writeln!(out, "{{"));
+		indent += 1;
+		try!(write_indent(out, indent));
+		try!(writeln!(out, "#[allow(dead_code)]"));
 
 		try!(write_indent(out, indent));
 		try!(writeln!(out, "#[repr({})]", unsafe { std::intrinsics::type_name::<IdType>() }));
@@ -194,12 +239,9 @@ impl<IdType: Numeric> IdResource<IdType>
 
 		try!(Self::gen_rust_enum_bare(out, ids, indent + 1));
 
-		if cfg!(feature = "c_api")
-		{
-			try!(write_indent(out, indent));
-			try!(writeln!(out, "}}"));
-			indent -= 1;
-		}
+		try!(write_indent(out, indent));
+		try!(writeln!(out, "}}"));
+		indent -= 1;
 		try!(write_indent(out, indent));
 		writeln!(out, "}}\n").map_err(|e| Error::IoError(e))
 	}
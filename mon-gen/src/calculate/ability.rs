@@ -0,0 +1,113 @@
+use rand::Rng;
+
+use base::effect::{AilmentCure, Effect, Modifier};
+use base::party::PartyMember;
+use base::runner::{BattleEffects, BattleState};
+use base::statmod::StatModifiers;
+use calculate::common::chance;
+use gen::element::Element;
+use types::monster::StatType;
+
+/// The fraction of maximum health at or below which pinch abilities take effect.
+const PINCH_DIVISOR: StatType = 3;
+
+/// The damage multiplier of pinch abilities.
+const PINCH_BONUS: f32 = 1.5;
+
+/// Adds the effects of the ability of every active party member at the end of a turn.
+pub fn turn_end<R: Rng>(effects: &mut BattleEffects, state: &BattleState, rng: &mut R)
+{
+	for (party_index, party) in state.parties().iter().enumerate()
+	{
+		for active_index in 0..party.active_count()
+		{
+			if let Some(member) = party.active_member_alive(active_index)
+			{
+				member.member.ability().turn_end(effects, party_index, active_index, state, rng);
+			}
+		}
+	}
+}
+
+fn modifier_add<F>(effects: &mut BattleEffects, party: usize, active: usize, modifier_func: F)
+	where F: Fn(&mut StatModifiers)
+{
+	let mut stats = Default::default();
+	modifier_func(&mut stats);
+	effects.effect_add(Effect::Modifier(Modifier::new(party, active, stats)));
+}
+
+/// Boosts attacks of the given element when the holder is low on health.
+pub fn pinch(offending: &PartyMember, attack_index: usize, _: &PartyMember, element: Element)
+	-> f32
+{
+	let attack = offending.member.attacks()[attack_index].attack();
+	if attack.element == element &&
+		offending.member.health() <= offending.member.stat_health() / PINCH_DIVISOR
+	{
+		PINCH_BONUS
+	}
+	else
+	{
+		1f32
+	}
+}
+
+/// Lowers the attack of every active party member on opposing sides by one stage.
+pub fn intimidate<R: Rng>(effects: &mut BattleEffects, party: usize, _: usize,
+	state: &BattleState, _: &mut R)
+{
+	let side = state.parties()[party].side();
+	for (target_party_index, target_party) in state.parties().iter().enumerate()
+	{
+		if target_party.side() == side
+		{
+			continue;
+		}
+		for target_active in 0..target_party.active_count()
+		{
+			if target_party.active_member_alive(target_active).is_some()
+			{
+				modifier_add(effects, target_party_index, target_active, |modifier|
+				{
+					modifier.attack_delta(-1);
+				});
+			}
+		}
+	}
+}
+
+/// Raises the defense of the holder by one stage.
+pub fn defense_raise<R: Rng>(effects: &mut BattleEffects, party: usize, active: usize,
+	_: &BattleState, _: &mut R)
+{
+	modifier_add(effects, party, active, |modifier|
+	{
+		modifier.defense_delta(1);
+	});
+}
+
+/// Raises the speed of the holder by one stage.
+pub fn speed_raise<R: Rng>(effects: &mut BattleEffects, party: usize, active: usize,
+	_: &BattleState, _: &mut R)
+{
+	modifier_add(effects, party, active, |modifier|
+	{
+		modifier.speed_delta(1);
+	});
+}
+
+/// Removes the ailment of the holder with the given probability.
+pub fn cure_chance<R: Rng>(effects: &mut BattleEffects, party: usize, active: usize,
+	state: &BattleState, rng: &mut R, probability: f32)
+{
+	let member = state.parties()[party].active_member(active);
+	if member.member.ailment().is_some() && chance(probability, rng)
+	{
+		effects.effect_add(Effect::AilmentCure(AilmentCure
+		{
+			party: party,
+			active: active,
+		}));
+	}
+}
@@ -0,0 +1,197 @@
+use std::cmp::max;
+
+use rand::Rng;
+use rand::distributions::{IndependentSample, Range};
+
+use base::command::CommandAttack;
+use base::effect::{AilmentCure, AilmentInflict, AilmentTick, Damage, DamageMeta, Effect,
+	NoneReason};
+use base::party::PartyMember;
+use base::runner::{BattleEffects, BattleState};
+use calculate::common::{chance, for_targets};
+use gen::attack::Ailment;
+use gen::element::Element;
+
+/// The chance that a paralyzed party member is unable to attack.
+const PARALYSIS_CHANCE: f32 = 0.25;
+
+/// The chance that a frozen party member thaws at the end of a turn.
+const THAW_CHANCE: f32 = 0.2;
+
+/// The maximum number of full turns a party member sleeps for.
+const SLEEP_TURNS_MAX: u8 = 3;
+
+/// The fraction of maximum health lost at the end of every turn from burn or poison.
+const TICK_DAMAGE_DIVISOR: u16 = 8;
+
+/// Whether an element is unaffected by the given ailment.
+fn is_immune(ailment: Ailment, elements: &[Element]) -> bool
+{
+	elements.iter().any(|element|
+	{
+		match (ailment, *element)
+		{
+			(Ailment::Burn, Element::Fire) => true,
+			(Ailment::Freeze, Element::Ice) => true,
+			(Ailment::Paralysis, Element::Electric) => true,
+			(Ailment::Poison, Element::Poison) | (Ailment::Poison, Element::Steel) => true,
+			_ => false,
+		}
+	})
+}
+
+/// Whether the ailment of the given party member keeps it from attacking this turn.
+///
+/// Uses `rng` only if the party member is paralyzed.
+///
+pub fn blocks_attack<R: Rng>(offending: &PartyMember, rng: &mut R) -> bool
+{
+	match offending.member.ailment()
+	{
+		Some(Ailment::Freeze) | Some(Ailment::Sleep) => true,
+		Some(Ailment::Paralysis) => chance(PARALYSIS_CHANCE, rng),
+		_ => false,
+	}
+}
+
+/// Adds the effects of every ailment of active party members at the end of a turn.
+///
+/// Burn and poison damage the affected party member, sleep counts down until the party member
+/// wakes up and freeze has a chance to thaw.
+///
+pub fn turn_end<R: Rng>(effects: &mut BattleEffects, state: &BattleState, rng: &mut R)
+{
+	for (party_index, party) in state.parties().iter().enumerate()
+	{
+		for active_index in 0..party.active_count()
+		{
+			let member = match party.active_member_alive(active_index)
+			{
+				Some(member) => member.member,
+				None => continue,
+			};
+			match member.ailment()
+			{
+				Some(Ailment::Burn) | Some(Ailment::Poison) =>
+				{
+					effects.effect_add(Effect::Damage(Damage
+					{
+						party: party_index,
+						active: active_index,
+						member: party.active_member_index(active_index),
+						meta: DamageMeta
+						{
+							amount: max(1, member.stat_health() / TICK_DAMAGE_DIVISOR),
+							type_bonus: 1.0,
+							critical: false,
+						}
+					}));
+				}
+				Some(Ailment::Sleep) if member.ailment_turns() != 0 =>
+				{
+					effects.effect_add(Effect::AilmentTick(AilmentTick
+					{
+						party: party_index,
+						active: active_index,
+					}));
+				}
+				Some(Ailment::Sleep) =>
+				{
+					effects.effect_add(Effect::AilmentCure(AilmentCure
+					{
+						party: party_index,
+						active: active_index,
+					}));
+				}
+				Some(Ailment::Freeze) =>
+				{
+					if chance(THAW_CHANCE, rng)
+					{
+						effects.effect_add(Effect::AilmentCure(AilmentCure
+						{
+							party: party_index,
+							active: active_index,
+						}));
+					}
+				}
+				_ => {}
+			}
+		}
+	}
+}
+
+// Adds an inflict effect for every target that can be given the ailment. Returns `true` if any
+// effect was added.
+fn inflict_targets<R: Rng>(effects: &mut BattleEffects, command: &CommandAttack, party: usize,
+	state: &BattleState, rng: &mut R, ailment: Ailment, probability: f32) -> bool
+{
+	let mut inflicted = false;
+	for_targets(command, party, state, |target_party, target_member|
+	{
+		let defending_member = state.parties()[target_party].active_member(target_member).member;
+		if defending_member.health() == 0 || defending_member.ailment().is_some() ||
+			is_immune(ailment, defending_member.get_elements()) || !chance(probability, rng)
+		{
+			return;
+		}
+
+		let turns = if ailment == Ailment::Sleep
+		{
+			Range::new(1, SLEEP_TURNS_MAX + 1).ind_sample(rng)
+		}
+		else
+		{
+			0
+		};
+		effects.effect_add(Effect::AilmentInflict(AilmentInflict
+		{
+			party: target_party,
+			active: target_member,
+			ailment: ailment,
+			turns: turns,
+		}));
+		inflicted = true;
+	});
+	inflicted
+}
+
+/// Gives the ailment to every target that does not already have one.
+pub fn inflict<R: Rng>(effects: &mut BattleEffects, command: &CommandAttack, party: usize,
+	state: &BattleState, rng: &mut R, ailment: Ailment)
+{
+	if !inflict_targets(effects, command, party, state, rng, ailment, 1.0)
+	{
+		effects.effect_add(Effect::None(NoneReason::None));
+	}
+}
+
+/// Gives the ailment to every target with the given probability, such as an attack side effect.
+pub fn inflict_chance<R: Rng>(effects: &mut BattleEffects, command: &CommandAttack,
+	party: usize, state: &BattleState, rng: &mut R, ailment: Ailment, probability: f32)
+{
+	inflict_targets(effects, command, party, state, rng, ailment, probability);
+}
+
+/// Removes the ailment of every target.
+pub fn cure<R: Rng>(effects: &mut BattleEffects, command: &CommandAttack, party: usize,
+	state: &BattleState, _: &mut R)
+{
+	let mut cured = false;
+	for_targets(command, party, state, |target_party, target_member|
+	{
+		let defending_member = state.parties()[target_party].active_member(target_member).member;
+		if defending_member.ailment().is_some()
+		{
+			effects.effect_add(Effect::AilmentCure(AilmentCure
+			{
+				party: target_party,
+				active: target_member,
+			}));
+			cured = true;
+		}
+	});
+	if !cured
+	{
+		effects.effect_add(Effect::None(NoneReason::None));
+	}
+}
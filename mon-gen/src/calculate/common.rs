@@ -1,12 +1,50 @@
 use rand::Rng;
+use rand::distributions::{IndependentSample, Range};
 
-use base::attack::Target;
+use base::attack::{Target, TargetType};
 use base::command::CommandAttack;
 use base::effect::{Damage, DamageMeta, Effect, FlagsChange, LingeringAdd, NoneReason, Retreat};
 use base::runner::{BattleFlagsType, BattleEffects, BattleState};
 use calculate::lingering::LingeringType;
 use types::monster::StatType;
 
+/// Returns `true` with the given probability.
+pub fn chance<R: Rng>(probability: f32, rng: &mut R) -> bool
+{
+	Range::new(0f32, 1f32).ind_sample(rng) < probability
+}
+
+/// Whether an attack with the given target flags used by an active member can hit the target.
+///
+/// Members on the same side are allies, even when they belong to different parties. Adjacency is
+/// decided by the field layout. See `BattleState::is_adjacent`.
+///
+pub fn target_valid(state: &BattleState, target: TargetType, party: usize, active: usize,
+	target_party: usize, target_active: usize) -> bool
+{
+	let same_side = state.parties()[party].side() == state.parties()[target_party].side();
+	if (same_side && target & Target::SIDE_ALLY == 0) ||
+		(!same_side && target & Target::SIDE_ENEMY == 0)
+	{
+		return false;
+	}
+
+	let is_adjacent = state.is_adjacent(party, active, target_party, target_active);
+	if (is_adjacent && target & Target::RANGE_ADJACENT == 0) ||
+		(!is_adjacent && target & Target::RANGE_OPPOSITE == 0)
+	{
+		return false;
+	}
+
+	let is_self = party == target_party && active == target_active;
+	!is_self || target & Target::TARGET_SELF != 0
+}
+
+/// Calls `closure` with the party and active member index of every member hit by the attack.
+///
+/// Single target attacks only hit the chosen target. Multi-target attacks hit every active member
+/// that is a valid target.
+///
 pub fn for_targets<F>(command: &CommandAttack, party: usize, state: &BattleState, mut closure: F)
 	where F: FnMut(usize, usize)
 {
@@ -23,28 +61,11 @@ pub fn for_targets<F>(command: &CommandAttack, party: usize, state: &BattleState
 	{
 		for party_index in 0..state.parties().len()
 		{
-			// TODO: This should be side check, not party check.
-			let same_side = party == party_index;
-			let hit_enemies = target & Target::SIDE_ENEMY != 0 && !same_side;
-			let hit_allies = target & Target::SIDE_ALLY != 0 && same_side;
-			
-			if hit_enemies || hit_allies
+			for active_index in 0..state.parties()[party_index].active_count()
 			{
-				for active_index in 0..state.parties()[party_index].active_count()
+				if target_valid(state, target, party, command.member, party_index, active_index)
 				{
-					let is_adjacent = Target::is_adjacent_with(command.member, active_index);
-					let hit_adjacent = target & Target::RANGE_ADJACENT != 0 && is_adjacent;
-					let hit_opposite = target & Target::RANGE_OPPOSITE != 0 && !is_adjacent;
-
-					if hit_adjacent || hit_opposite
-					{
-						let not_self = target & Target::TARGET_SELF == 0;
-						let is_self = party_index == party && active_index == command.member;
-						if not_self || !is_self
-						{
-							closure(party_index, active_index);
-						}
-					}
+					closure(party_index, active_index);
 				}
 			}
 		}
@@ -7,25 +7,45 @@ use base::command::CommandAttack;
 use base::effect::{Damage, DamageMeta, Effect, NoneReason};
 use base::party::PartyMember;
 use base::runner::{BattleEffects, BattleState};
+#[cfg(not(feature = "test"))]
+use calculate::common::chance;
 use calculate::common::for_targets;
-use gen::attack::Category;
+use gen::attack::{Ailment, Category};
 use types::attack::AccuracyType;
 use types::battle::StatModifierType;
 use types::monster::StatType;
 
+/// The chance from 0 to 1 of an attack used by the offending member hitting the defending member.
+///
+/// Attacks without an accuracy never miss. Otherwise, the accuracy of the attack is scaled by the
+/// accuracy stage of the offending member after the evasion stage of the defending member is
+/// taken away from it.
+///
+pub fn hit_chance(offending: &PartyMember, attack_index: usize, defending: &PartyMember)
+	-> AccuracyType
+{
+	match offending.member.attacks()[attack_index].attack().accuracy
+	{
+		Some(accuracy) =>
+		{
+			let modifier = offending.modifiers.accuracy_value_against(&defending.modifiers);
+			(accuracy * modifier).min(1.0)
+		}
+		None => 1.0,
+	}
+}
+
 #[cfg(feature = "test")]
-pub fn calculate_miss<R: Rng>(_: &PartyMember, _: usize, _: &mut R) -> bool
+pub fn calculate_miss<R: Rng>(_: &PartyMember, _: usize, _: &PartyMember, _: &mut R) -> bool
 {
 	false
 }
 
 #[cfg(not(feature = "test"))]
-pub fn calculate_miss<R: Rng>(offending: &PartyMember, attack_index: usize, rng: &mut R) -> bool
+pub fn calculate_miss<R: Rng>(offending: &PartyMember, attack_index: usize,
+	defending: &PartyMember, rng: &mut R) -> bool
 {
-	let attack = offending.member.attacks()[attack_index].attack();
-	let range = Range::new(0.0 as AccuracyType, 1.0 as AccuracyType);
-	let chance = offending.modifiers.accuracy_value() / offending.modifiers.evasion_value();
-	range.ind_sample(rng) > attack.accuracy / chance
+	!chance(hit_chance(offending, attack_index, defending), rng)
 }
 
 #[cfg(feature = "test")]
@@ -67,6 +87,19 @@ pub fn calculate_damage_randomness(offending: &PartyMember, attack_index: usize,
 		}
 	}
 
+	// Burned members deal less physical damage.
+	if attack.category == Category::Physical && offending.member.ailment() == Some(Ailment::Burn)
+	{
+		bonus *= 0.5f32;
+	}
+
+	// Ability and held item attack bonus.
+	bonus *= offending.member.ability().before_damage(offending, attack_index, defending);
+	if let Some(held_item) = offending.member.held_item()
+	{
+		bonus *= held_item.before_damage(offending, attack_index, defending);
+	}
+
 	// Critical attack bonus.
 	bonus *= if critical
 	{
@@ -89,7 +122,9 @@ pub fn miss_or<R: Rng, F>(effects: &mut BattleEffects, command: &CommandAttack,
 		where F: Fn(&mut BattleEffects, &CommandAttack, usize, &BattleState, &mut R)
 {
 	let attacking_member = &state.parties()[party].active_member(command.member);
-	if calculate_miss(attacking_member, command.attack_index, rng)
+	let defending_member = &state.parties()[command.target_party].active_member(
+		command.target_member);
+	if calculate_miss(attacking_member, command.attack_index, defending_member, rng)
 	{
 		effects.effect_add(Effect::None(NoneReason::Miss));
 	}
@@ -144,9 +179,9 @@ pub fn damage<R: Rng>(effects: &mut BattleEffects, command: &CommandAttack, part
 
 		let damage = Damage
 		{
-			party: command.target_party,
-			active: command.target_member,
-			member: defending_party.active_member_index(command.target_member),
+			party: target_party,
+			active: target_member,
+			member: defending_party.active_member_index(target_member),
 			meta: DamageMeta
 			{
 				amount: amount,
@@ -157,3 +192,23 @@ pub fn damage<R: Rng>(effects: &mut BattleEffects, command: &CommandAttack, part
 		effects.effect_add(Effect::Damage(damage));
 	});
 }
+
+/// Makes the attacking party member faint, such as after exploding. This happens even if the attack
+/// missed.
+pub fn faint<R: Rng>(effects: &mut BattleEffects, command: &CommandAttack, party: usize,
+	state: &BattleState, _: &mut R)
+{
+	let attacking_party = &state.parties()[party];
+	effects.effect_add(Effect::Damage(Damage
+	{
+		party: party,
+		active: command.member,
+		member: attacking_party.active_member_index(command.member),
+		meta: DamageMeta
+		{
+			amount: attacking_party.active_member(command.member).member.health(),
+			type_bonus: 1.0,
+			critical: false,
+		}
+	}));
+}
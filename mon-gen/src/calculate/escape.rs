@@ -0,0 +1,60 @@
+use rand::Rng;
+
+use base::command::CommandEscape;
+use base::effect::{Effect, EscapeFailure, EscapeSuccess};
+use base::party::Party;
+use base::runner::{BattleEffects, BattleState};
+use calculate::common::chance;
+use types::monster::StatType;
+
+/// The chance of escaping added for every prior failed attempt.
+const ATTEMPT_BONUS: f32 = 30.0 / 256.0;
+
+/// The chance of escaping for a party whose fastest active member has the given speed.
+///
+/// Escaping always succeeds when the party is at least as fast as the fastest enemy. Otherwise, it
+/// gets likelier with every prior failed attempt.
+///
+pub fn escape_chance(speed: StatType, enemy_speed: StatType, attempts: u8) -> f32
+{
+	if speed >= enemy_speed
+	{
+		1f32
+	}
+	else
+	{
+		let speed_ratio = speed as f32 / enemy_speed as f32 / 2f32;
+		(speed_ratio + attempts as f32 * ATTEMPT_BONUS).min(1f32)
+	}
+}
+
+// The speed of the fastest active member of the party.
+fn party_speed(party: &Party) -> StatType
+{
+	(0..party.active_count()).map(|active| party.active_member(active).speed()).max().unwrap_or(0)
+}
+
+/// Adds the result of the party attempting to escape against every party on another side.
+pub fn effects<R: Rng>(effects: &mut BattleEffects, command: &CommandEscape,
+	state: &BattleState, rng: &mut R)
+{
+	let party = &state.parties()[command.party];
+	let enemy_speed = state.parties().iter().filter(|other| other.side() != party.side())
+		.map(party_speed).max().unwrap_or(0);
+	let probability = escape_chance(party_speed(party), enemy_speed, party.escape_attempts());
+
+	if chance(probability, rng)
+	{
+		effects.effect_add(Effect::EscapeSuccess(EscapeSuccess
+		{
+			party: command.party,
+		}));
+	}
+	else
+	{
+		effects.effect_add(Effect::EscapeFailure(EscapeFailure
+		{
+			party: command.party,
+		}));
+	}
+}
@@ -1,8 +1,20 @@
+use std::cmp::max;
 use std::collections::HashMap;
 
-use base::party::Party;
+use base::monster::STAT_COUNT;
+use base::party::{ExperienceShare, Party, PartyKind};
 use gen::species::Growth;
 use types::monster::{ExperienceType, LevelType};
+use types::species::StatYieldType;
+
+/// The experience multiplier for defeating members of a trainer's party.
+const TRAINER_BONUS: f32 = 1.5;
+
+/// The fraction of experience given to members that did not battle with `ExperienceShare::Share`.
+const SHARE_FRACTION: f32 = 0.5;
+
+/// The experience multiplier for members that belong to someone other than their original trainer.
+const TRADE_BONUS: f32 = 1.5;
 
 pub struct MemberIndex
 {
@@ -77,29 +89,70 @@ impl Growth
 pub fn calculate_experience(parties: &[Party], _: Option<MemberIndex>,
 	defense: MemberIndex) -> HashMap<usize, HashMap<usize, ExperienceType>>
 {
-	// TODO: Bonus is 1.5 if battling a non-wild trainer.
-	let bonus = 1.0f32;
+	let mut party_map = HashMap::new();
+	let bonus = match parties[defense.party].kind()
+	{
+		PartyKind::Wild => 1f32,
+		PartyKind::Trainer => TRAINER_BONUS,
+		PartyKind::Link => return party_map,
+	};
 
 	let defense_member = parties[defense.party].active_member(defense.member).member;
 	let base_yield = defense_member.species().species().experience_yield as f32;
 	let level = defense_member.level() as f32;
 
-	// TODO: Bigger bonus if monster is traded.
-	let trade_bonus = 1.0f32;
-
-	let gain = ((bonus * trade_bonus * base_yield * level) / 7f32).round() as ExperienceType;
-
-	let mut party_map = HashMap::new();
+	let gain = bonus * base_yield * level / 7f32;
 
 	let exposed = parties[defense.party].expose_get_member(defense.member);
 	for exposed_party in exposed
 	{
+		let party = &parties[*exposed_party.0];
 		let member_map = party_map.entry(*exposed_party.0).or_insert_with(HashMap::new);
+		let exposed_gain = match party.experience_share()
+		{
+			ExperienceShare::Split => gain / exposed_party.1.len() as f32,
+			ExperienceShare::Exposed | ExperienceShare::Share => gain,
+		};
 		for exposed_member in exposed_party.1
 		{
-			member_map.insert(*exposed_member, gain);
+			member_map.insert(*exposed_member, member_gain(party, *exposed_member, exposed_gain));
+		}
+
+		if party.experience_share() == ExperienceShare::Share
+		{
+			for member in 0..party.member_count()
+			{
+				if !exposed_party.1.contains(&member) && party.member(member).health() != 0
+				{
+					member_map.insert(member, member_gain(party, member, gain * SHARE_FRACTION));
+				}
+			}
 		}
 	}
 
 	party_map
 }
+
+// The experience gained by the given member after bonuses, which is always at least 1.
+fn member_gain(party: &Party, member: usize, gain: f32) -> ExperienceType
+{
+	let trade_bonus = if party.member(member).traded(party.trainer())
+	{
+		TRADE_BONUS
+	}
+	else
+	{
+		1f32
+	};
+	max((gain * trade_bonus).round() as ExperienceType, 1)
+}
+
+/// The effort values yielded by the defeated member to every member that gains experience from it.
+pub fn calculate_efforts(parties: &[Party], defense: MemberIndex) -> [StatYieldType; STAT_COUNT]
+{
+	let defense_member = parties[defense.party].active_member(defense.member).member;
+	let species = defense_member.species().species();
+	let form = defense_member.form() as usize;
+	[species.yield_health[form], species.yield_attack[form], species.yield_defense[form],
+		species.yield_spattack[form], species.yield_spdefense[form], species.yield_speed[form]]
+}
@@ -0,0 +1,188 @@
+use std::cmp::{max, min};
+
+use rand::Rng;
+
+use base::command::CommandItem;
+use base::effect::{AilmentCure, AilmentRemove, Effect, Heal, HealthRestore, ItemConsume,
+	LimitRestore};
+use base::party::PartyMember;
+use base::runner::{BattleEffects, BattleState};
+use gen::attack::Ailment;
+use gen::element::Element;
+use types::attack::LimitType;
+use types::monster::StatType;
+
+/// The fraction of maximum health at or below which berries take effect.
+const BERRY_DIVISOR: StatType = 2;
+
+/// The damage multiplier of items that boost an element.
+const ELEMENT_BONUS: f32 = 1.2;
+
+/// Adds the effects of the held item of every active party member at the end of a turn.
+pub fn turn_end<R: Rng>(effects: &mut BattleEffects, state: &BattleState, rng: &mut R)
+{
+	for (party_index, party) in state.parties().iter().enumerate()
+	{
+		for active_index in 0..party.active_count()
+		{
+			if let Some(held_item) = party.active_member_alive(active_index).and_then(|member|
+			{
+				member.member.held_item()
+			})
+			{
+				held_item.turn_end(effects, party_index, active_index, state, rng);
+			}
+		}
+	}
+}
+
+// Adds an effect that uses up the held item of the given active party member.
+fn consume(effects: &mut BattleEffects, party: usize, active: usize, state: &BattleState)
+{
+	let member = state.parties()[party].active_member(active);
+	if let Some(held_item) = member.member.held_item()
+	{
+		effects.effect_add(Effect::ItemConsume(ItemConsume
+		{
+			party: party,
+			active: active,
+			item: held_item,
+		}));
+	}
+}
+
+// Adds a heal effect for the missing health of the given active party member, up to `amount`.
+fn heal(effects: &mut BattleEffects, party: usize, active: usize, state: &BattleState,
+	amount: StatType) -> bool
+{
+	let member = state.parties()[party].active_member(active).member;
+	let missing = member.stat_health() - member.health();
+	if missing == 0
+	{
+		return false;
+	}
+	effects.effect_add(Effect::Heal(Heal
+	{
+		party: party,
+		active: active,
+		amount: min(missing, amount),
+	}));
+	true
+}
+
+fn is_pinch(member: &PartyMember) -> bool
+{
+	member.member.health() <= member.member.stat_health() / BERRY_DIVISOR
+}
+
+/// Boosts attacks of the given element.
+pub fn element_boost(offending: &PartyMember, attack_index: usize, _: &PartyMember,
+	element: Element) -> f32
+{
+	if offending.member.attacks()[attack_index].attack().element == element
+	{
+		ELEMENT_BONUS
+	}
+	else
+	{
+		1f32
+	}
+}
+
+/// Restores a fraction of maximum health of the holder.
+pub fn heal_fraction<R: Rng>(effects: &mut BattleEffects, party: usize, active: usize,
+	state: &BattleState, _: &mut R, divisor: StatType)
+{
+	let amount = max(1, state.parties()[party].active_member(active).member.stat_health() /
+		divisor);
+	heal(effects, party, active, state, amount);
+}
+
+/// Restores a fixed amount of health once the holder is low on health, using up the item.
+pub fn berry_heal<R: Rng>(effects: &mut BattleEffects, party: usize, active: usize,
+	state: &BattleState, _: &mut R, amount: StatType)
+{
+	if is_pinch(&state.parties()[party].active_member(active)) &&
+		heal(effects, party, active, state, amount)
+	{
+		consume(effects, party, active, state);
+	}
+}
+
+/// Restores a fraction of maximum health once the holder is low on health, using up the item.
+pub fn berry_heal_fraction<R: Rng>(effects: &mut BattleEffects, party: usize, active: usize,
+	state: &BattleState, rng: &mut R, divisor: StatType)
+{
+	let amount = max(1, state.parties()[party].active_member(active).member.stat_health() /
+		divisor);
+	berry_heal(effects, party, active, state, rng, amount);
+}
+
+/// Removes the given ailment of the holder, or any ailment if `None`, using up the item.
+pub fn berry_cure<R: Rng>(effects: &mut BattleEffects, party: usize, active: usize,
+	state: &BattleState, _: &mut R, ailment: Option<Ailment>)
+{
+	let current = state.parties()[party].active_member(active).member.ailment();
+	if current.is_some() && (ailment.is_none() || current == ailment)
+	{
+		effects.effect_add(Effect::AilmentCure(AilmentCure
+		{
+			party: party,
+			active: active,
+		}));
+		consume(effects, party, active, state);
+	}
+}
+
+/// Restores health of the target, up to `amount`.
+pub fn restore_health<R: Rng>(effects: &mut BattleEffects, command: &CommandItem,
+	state: &BattleState, _: &mut R, amount: StatType)
+{
+	let member = state.parties()[command.party].member(command.target);
+	let missing = member.stat_health() - member.health();
+	if missing != 0
+	{
+		effects.effect_add(Effect::HealthRestore(HealthRestore
+		{
+			party: command.party,
+			member: command.target,
+			amount: min(missing, amount),
+		}));
+	}
+}
+
+/// Removes the given ailment of the target, or any ailment if `None`.
+pub fn restore_ailment<R: Rng>(effects: &mut BattleEffects, command: &CommandItem,
+	state: &BattleState, _: &mut R, ailment: Option<Ailment>)
+{
+	let current = state.parties()[command.party].member(command.target).ailment();
+	if current.is_some() && (ailment.is_none() || current == ailment)
+	{
+		effects.effect_add(Effect::AilmentRemove(AilmentRemove
+		{
+			party: command.party,
+			member: command.target,
+		}));
+	}
+}
+
+/// Restores the use limit of every attack of the target, up to `amount` each.
+pub fn restore_limit<R: Rng>(effects: &mut BattleEffects, command: &CommandItem,
+	state: &BattleState, _: &mut R, amount: LimitType)
+{
+	let member = state.parties()[command.party].member(command.target);
+	for (attack_index, attack) in member.attacks().iter().enumerate()
+	{
+		let missing = attack.limit_max().saturating_sub(attack.limit_left());
+		if missing != 0
+		{
+			effects.effect_add(Effect::LimitRestore(LimitRestore
+			{
+				party: command.party,
+				member: command.target,
+				attack: attack_index,
+				amount: min(missing, amount),
+			}));
+		}
+	}
+}
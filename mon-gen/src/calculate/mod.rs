@@ -1,6 +1,13 @@
+pub mod ability;
+pub mod ailment;
 pub mod common;
 pub mod effects;
+pub mod escape;
 pub mod experience;
+pub mod item;
 pub mod modifier;
+pub mod personality;
+pub mod recruit;
 pub mod statistics;
-pub mod lingering;
\ No newline at end of file
+pub mod volatile;
+pub mod lingering;
@@ -0,0 +1,29 @@
+use types::monster::{PersonalityType, TrainerIdType};
+
+/// Shiny values below this are shiny, making one in every 8192 personalities shiny for a trainer.
+pub const SHINY_THRESHOLD: u32 = 8;
+
+/// The value that decides whether the personality is shiny for the given trainer.
+///
+/// The upper and lower halves of both the trainer identifier and the personality are combined with
+/// an exclusive or. The same personality is therefore shiny for some trainers but not others.
+///
+pub fn shiny_value(personality: PersonalityType, trainer: TrainerIdType) -> u32
+{
+	(trainer >> 16) ^ (trainer & 0xFFFF) ^ (personality >> 16) ^ (personality & 0xFFFF)
+}
+
+/// Whether the personality is shiny for the given trainer.
+pub fn shiny(personality: PersonalityType, trainer: TrainerIdType) -> bool
+{
+	shiny_value(personality, trainer) < SHINY_THRESHOLD
+}
+
+/// The index of the ability out of the given number of abilities that the personality has.
+///
+/// The second lowest byte decides the ability, so that it does not depend on the gender.
+///
+pub fn ability_slot(personality: PersonalityType, count: usize) -> usize
+{
+	((personality >> 8) & 0xFF) as usize % count
+}
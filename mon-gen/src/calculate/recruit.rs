@@ -0,0 +1,101 @@
+use std::cmp::min;
+
+use rand::Rng;
+
+use base::command::CommandRecruit;
+use base::effect::{Effect, RecruitFailure, RecruitShake, RecruitSuccess};
+use base::monster::Monster;
+use base::runner::{BattleEffects, BattleState};
+use calculate::common::chance;
+use gen::attack::Ailment;
+use gen::monster::RecruitMethod;
+
+/// The modified recruit rate at or above which recruiting always succeeds.
+const RATE_MAX: f32 = 255.0;
+
+/// The number of shake checks that must all pass for recruiting to succeed.
+const SHAKE_COUNT: u8 = 4;
+
+/// The recruit rate multiplier for members that are asleep or frozen.
+const AILMENT_BONUS_HIGH: f32 = 2.0;
+
+/// The recruit rate multiplier for members with any other ailment.
+const AILMENT_BONUS_LOW: f32 = 1.5;
+
+/// The modified recruit rate for the given member using the given method.
+///
+/// Rarer species and members with more health left are harder to recruit. Ailments make it easier.
+///
+pub fn recruit_rate(member: &Monster, method: RecruitMethod) -> f32
+{
+	let health_max = member.stat_health() as f32;
+	let health = member.health() as f32;
+	let ailment_bonus = match member.ailment()
+	{
+		Some(Ailment::Sleep) | Some(Ailment::Freeze) => AILMENT_BONUS_HIGH,
+		Some(_) => AILMENT_BONUS_LOW,
+		None => 1f32,
+	};
+	let rareness = member.species().species().rareness as f32;
+	(3f32 * health_max - 2f32 * health) * rareness * method.rate() / (3f32 * health_max) *
+		ailment_bonus
+}
+
+/// The chance of every individual shake check passing for the given modified recruit rate.
+pub fn shake_chance(rate: f32) -> f32
+{
+	if rate >= RATE_MAX
+	{
+		1f32
+	}
+	else
+	{
+		(rate / RATE_MAX).powf(0.25)
+	}
+}
+
+/// Adds a shake effect for every passed shake check followed by the result of recruiting.
+pub fn effects<R: Rng>(effects: &mut BattleEffects, command: &CommandRecruit,
+	state: &BattleState, rng: &mut R)
+{
+	let probability =
+	{
+		let target = state.parties()[command.target_party].active_member(command.target_member);
+		shake_chance(recruit_rate(target.member, command.method))
+	};
+
+	let mut shakes = 0;
+	while shakes < SHAKE_COUNT && chance(probability, rng)
+	{
+		shakes += 1;
+	}
+
+	// Only shakes before the final check are shown.
+	for _ in 0..min(shakes, SHAKE_COUNT - 1)
+	{
+		effects.effect_add(Effect::RecruitShake(RecruitShake
+		{
+			party: command.target_party,
+			active: command.target_member,
+		}));
+	}
+
+	if shakes == SHAKE_COUNT
+	{
+		effects.effect_add(Effect::RecruitSuccess(RecruitSuccess
+		{
+			party: command.party,
+			target_party: command.target_party,
+			target_active: command.target_member,
+			method: command.method,
+		}));
+	}
+	else
+	{
+		effects.effect_add(Effect::RecruitFailure(RecruitFailure
+		{
+			party: command.target_party,
+			active: command.target_member,
+		}));
+	}
+}
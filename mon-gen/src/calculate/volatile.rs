@@ -0,0 +1,266 @@
+use std::cmp::max;
+
+use rand::Rng;
+use rand::distributions::{IndependentSample, Range};
+
+use base::command::CommandAttack;
+use base::effect::{Damage, DamageMeta, Effect, Heal, NoneReason, VolatileAdd, VolatileRemove};
+use base::party::PartyMember;
+use base::runner::{BattleEffects, BattleState};
+use base::volatile::{Volatile, VolatileType};
+use calculate::common::{chance, for_targets};
+use gen::element::Element;
+use types::monster::StatType;
+
+/// The chance that a confused party member hurts itself instead of attacking.
+const CONFUSION_CHANCE: f32 = 1.0 / 3.0;
+
+/// The base power of the typeless attack a confused party member hurts itself with.
+const CONFUSION_POWER: f32 = 40.0;
+
+/// The range of turns a party member stays confused for.
+const CONFUSION_TURNS: (u8, u8) = (1, 5);
+
+/// The range of turns a party member stays trapped for.
+const TRAP_TURNS: (u8, u8) = (4, 6);
+
+/// The fraction of maximum health drained at the end of every turn by leech seed.
+const LEECH_SEED_DIVISOR: StatType = 8;
+
+fn confusion_damage(member: &PartyMember) -> StatType
+{
+	max(1, (((2 * member.member.level() + 10) as f32 / 250f32) *
+		(member.attack() as f32 / member.defense() as f32) * CONFUSION_POWER + 2f32).floor()
+		as StatType)
+}
+
+/// Generates effects for the volatile conditions of the attacking party member before its attack.
+///
+/// Returns `false` if the attack is cancelled, in which case the effects of the attack must not be
+/// generated. Flinching cancels the attack and confusion may replace it with damage to the
+/// attacking party member itself.
+///
+pub fn before_attack<R: Rng>(effects: &mut BattleEffects, command: &CommandAttack,
+	state: &BattleState, rng: &mut R) -> bool
+{
+	let offending = state.parties()[command.party].active_member(command.member);
+	if offending.volatile_has(VolatileType::Flinch)
+	{
+		effects.effect_add(Effect::None(NoneReason::Flinch));
+		return false;
+	}
+
+	for volatile in offending.volatile()
+	{
+		if let Volatile::Confusion(turns) = *volatile
+		{
+			if turns == 0
+			{
+				effects.effect_add(Effect::VolatileRemove(VolatileRemove
+				{
+					party: command.party,
+					active: command.member,
+					volatile: VolatileType::Confusion,
+				}));
+			}
+			else
+			{
+				effects.effect_add(Effect::VolatileAdd(VolatileAdd
+				{
+					party: command.party,
+					active: command.member,
+					volatile: Volatile::Confusion(turns - 1),
+				}));
+				if chance(CONFUSION_CHANCE, rng)
+				{
+					effects.effect_add(Effect::Damage(Damage
+					{
+						party: command.party,
+						active: command.member,
+						member: state.parties()[command.party].active_member_index(command.member),
+						meta: DamageMeta
+						{
+							amount: confusion_damage(&offending),
+							type_bonus: 1.0,
+							critical: false,
+						}
+					}));
+					return false;
+				}
+			}
+		}
+	}
+	true
+}
+
+/// Adds the effects of every volatile condition of active party members at the end of a turn.
+///
+/// Flinching wears off, trapping counts down and leech seed drains health to the member that
+/// planted it.
+///
+pub fn turn_end(effects: &mut BattleEffects, state: &BattleState)
+{
+	for (party_index, party) in state.parties().iter().enumerate()
+	{
+		for active_index in 0..party.active_count()
+		{
+			let member = match party.active_member_alive(active_index)
+			{
+				Some(member) => member,
+				None => continue,
+			};
+			for volatile in member.volatile()
+			{
+				match *volatile
+				{
+					Volatile::Flinch | Volatile::Trap(0, _, _) =>
+					{
+						effects.effect_add(Effect::VolatileRemove(VolatileRemove
+						{
+							party: party_index,
+							active: active_index,
+							volatile: volatile.volatile_type(),
+						}));
+					}
+					Volatile::Trap(turns, source_party, source_member) =>
+					{
+						effects.effect_add(Effect::VolatileAdd(VolatileAdd
+						{
+							party: party_index,
+							active: active_index,
+							volatile: Volatile::Trap(turns - 1, source_party, source_member),
+						}));
+					}
+					Volatile::LeechSeed(source_party, source_member) =>
+					{
+						let amount = max(1, member.member.stat_health() / LEECH_SEED_DIVISOR);
+						effects.effect_add(Effect::Damage(Damage
+						{
+							party: party_index,
+							active: active_index,
+							member: party.active_member_index(active_index),
+							meta: DamageMeta
+							{
+								amount: amount,
+								type_bonus: 1.0,
+								critical: false,
+							}
+						}));
+
+						// The planting member may have fainted and been taken off the field.
+						let source = &state.parties()[source_party];
+						if let Some(source_active) = source.active_find_reference(source_member)
+						{
+							effects.effect_add(Effect::Heal(Heal
+							{
+								party: source_party,
+								active: source_active,
+								amount: amount,
+							}));
+						}
+					}
+					Volatile::Confusion(_) => {}
+				}
+			}
+		}
+	}
+}
+
+// Adds the volatile condition created by `create` to every target that does not already have a
+// condition of the same type. Returns `true` if any condition was added.
+fn add_targets<R: Rng, F>(effects: &mut BattleEffects, command: &CommandAttack, party: usize,
+	state: &BattleState, rng: &mut R, volatile_type: VolatileType, probability: f32,
+	mut create: F) -> bool where F: FnMut(&PartyMember, &mut R) -> Option<Volatile>
+{
+	let mut added = false;
+	for_targets(command, party, state, |target_party, target_member|
+	{
+		let defending = state.parties()[target_party].active_member(target_member);
+		if defending.member.health() == 0 || defending.volatile_has(volatile_type) ||
+			!chance(probability, rng)
+		{
+			return;
+		}
+		if let Some(volatile) = create(&defending, rng)
+		{
+			effects.effect_add(Effect::VolatileAdd(VolatileAdd
+			{
+				party: target_party,
+				active: target_member,
+				volatile: volatile,
+			}));
+			added = true;
+		}
+	});
+	added
+}
+
+fn nothing_if(effects: &mut BattleEffects, added: bool)
+{
+	if !added
+	{
+		effects.effect_add(Effect::None(NoneReason::None));
+	}
+}
+
+/// Confuses every target for a random number of turns.
+pub fn confuse<R: Rng>(effects: &mut BattleEffects, command: &CommandAttack, party: usize,
+	state: &BattleState, rng: &mut R)
+{
+	let added = add_targets(effects, command, party, state, rng, VolatileType::Confusion, 1.0,
+		|_, rng|
+	{
+		Some(Volatile::Confusion(Range::new(CONFUSION_TURNS.0, CONFUSION_TURNS.1).ind_sample(rng)))
+	});
+	nothing_if(effects, added);
+}
+
+/// Makes every target flinch with the given probability, such as an attack side effect.
+///
+/// Flinching only has an effect on targets that have not attacked yet this turn.
+///
+pub fn flinch_chance<R: Rng>(effects: &mut BattleEffects, command: &CommandAttack, party: usize,
+	state: &BattleState, rng: &mut R, probability: f32)
+{
+	add_targets(effects, command, party, state, rng, VolatileType::Flinch, probability, |_, _|
+	{
+		Some(Volatile::Flinch)
+	});
+}
+
+/// Keeps every target from switching out for a random number of turns or until the attacking
+/// party member switches out.
+pub fn trap<R: Rng>(effects: &mut BattleEffects, command: &CommandAttack, party: usize,
+	state: &BattleState, rng: &mut R)
+{
+	let source_member = state.parties()[party].active_member_reference(command.member);
+	add_targets(effects, command, party, state, rng, VolatileType::Trap, 1.0, |_, rng|
+	{
+		let turns = Range::new(TRAP_TURNS.0, TRAP_TURNS.1).ind_sample(rng);
+		Some(Volatile::Trap(turns, party, source_member))
+	});
+}
+
+/// Plants a seed on every target that drains health to the attacking party member every turn until
+/// it switches out.
+///
+/// Grass elements are unaffected.
+///
+pub fn leech_seed<R: Rng>(effects: &mut BattleEffects, command: &CommandAttack, party: usize,
+	state: &BattleState, rng: &mut R)
+{
+	let source_member = state.parties()[party].active_member_reference(command.member);
+	let added = add_targets(effects, command, party, state, rng, VolatileType::LeechSeed, 1.0,
+		|defending, _|
+	{
+		if defending.member.get_elements().iter().any(|element| *element == Element::Grass)
+		{
+			None
+		}
+		else
+		{
+			Some(Volatile::LeechSeed(party, source_member))
+		}
+	});
+	nothing_if(effects, added);
+}
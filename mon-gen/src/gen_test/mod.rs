@@ -1,8 +1,10 @@
+pub mod ability_list;
 pub mod attack;
 pub mod attack_list;
 pub mod element;
 pub mod gender;
-// pub mod locations;
+pub mod item_list;
+pub mod locations;
 pub mod monster;
 pub mod species;
 pub mod species_list;
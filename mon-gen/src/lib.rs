@@ -2,6 +2,7 @@
 
 extern crate rand;
 extern crate num;
+extern crate toml;
 #[macro_use] extern crate custom_derive;
 #[macro_use] extern crate enum_derive;
 #[macro_use] extern crate enum_primitive;
@@ -34,31 +35,66 @@ mod_gen_default!(false);
 pub mod attack
 {
 	pub use base::attack::*;
+	pub use gen::attack::*;
 	pub use gen::attack_list::*;
 	pub use types::attack::*;
 }
 
+/// Traits of party members that take effect on their own during battle.
+pub mod ability
+{
+	pub use base::ability::*;
+	pub use gen::ability_list::*;
+	pub use types::species::AbilityId;
+}
+
+/// Objects held by party members that take effect on their own during battle.
+pub mod item
+{
+	pub use base::item::*;
+	pub use gen::item_list::*;
+	pub use types::item::*;
+}
+
 /// Parties and versing between them.
 pub mod battle
 {
 	pub use base::party::*;
 	pub use base::runner::BattleRunner;
 	pub use base::runner::BattleExecution;
+	pub use base::runner::BattleOutcome;
 	pub use base::runner::BattlePartyMember;
 	pub use base::queue::BattleQueue;
+	pub use base::replay::{BattleReplay, BattleReplayLearn, BattleReplayParty, BattleReplayStep};
+	pub use base::player::BattleReplayPlayer;
 	pub use base::battle::Battle;
 	pub use base::battle::BattleError;
 	pub use base::command::*;
 	pub use base::effect::*;
 	pub use base::statmod::*;
+	pub use base::target::*;
+	pub use base::volatile::*;
 	pub use types::battle::*;
 	pub use calculate::lingering::*;
+	pub use calculate::escape::escape_chance;
+}
+
+/// Saving and loading of monsters and battle replays.
+pub mod serialize
+{
+	pub use base::serialize::{SerializeError, SerializeResult};
+	pub use base::replay::REPLAY_VERSION;
+	pub use base::monster::MONSTER_VERSION;
 }
 
 /// Party members with meta-data and actions.
 pub mod monster
 {
 	pub use base::monster::*;
+	pub use calculate::personality::*;
+	pub use gen::locations::*;
+	pub use gen::monster::*;
+	pub use types::generic::*;
 	pub use types::monster::*;
 }
 
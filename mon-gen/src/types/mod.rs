@@ -7,6 +7,9 @@ pub mod generic
 
 	/// The sub-location identifier, as paired with `LocationId`.
 	pub type SubLocationId = u8;
+
+	/// A date in seconds since the Unix epoch.
+	pub type DateType = u32;
 }
 
 pub mod element
@@ -37,6 +40,18 @@ pub mod attack
 
 	/// The attack priority order type for `Attack`.
 	pub type PriorityType = i8;
+
+	/// The identifier value for non-volatile ailments, `Ailment` enum.
+	pub type AilmentId = u8;
+}
+
+pub mod item
+{
+	/// The identifier type for `Item`.
+	pub type ItemId = u16;
+
+	/// The value type for the number of items of the same type stored in a bag.
+	pub type ItemCountType = u8;
 }
 
 pub mod gender
@@ -98,9 +113,12 @@ pub mod monster
 	/// The value type for `Monster` displaying how much experience it has gained.
 	pub type LevelType = u8;
 
-	/// Tge random personality value, unique to each `Monster`.
+	/// The random personality value, unique to each `Monster`.
 	pub type PersonalityType = u32;
 
+	/// The identifier of a trainer, used together with `PersonalityType` to decide shininess.
+	pub type TrainerIdType = u32;
+
 	/// The identifier type for `Monster` natures, `Nature` enum.
 	pub type NatureId = u8;
 
@@ -121,4 +139,13 @@ pub mod battle
 {
 	/// The type storing dynamic statistic modifiers while in battle.
 	pub type StatModifierType = i8;
+
+	/// The identifier type for `PartyKind`.
+	pub type PartyKindId = u8;
+
+	/// The identifier type for `ExperienceShare`.
+	pub type ExperienceShareId = u8;
+
+	/// The identifier type for `FieldLayout`.
+	pub type FieldLayoutId = u8;
 }
@@ -0,0 +1,106 @@
+extern crate mon_gen;
+
+mod common;
+
+use mon_gen::ability::AbilityType;
+use mon_gen::attack::AttackType;
+use mon_gen::battle::{Battle, BattleError, Party, StatModifiers};
+use mon_gen::monster::Monster;
+use mon_gen::species::SpeciesType;
+
+use common::{first_damage, monster_splash, turn_effects};
+
+// Validate that monsters are given one of the default abilities of their species.
+#[test]
+fn ability_species()
+{
+	let species = SpeciesType::Pidgey.species();
+	assert_eq!(species.abilities, &[AbilityType::KeenEye, AbilityType::TangledFeet]);
+	assert_eq!(species.abilities_hidden, &[AbilityType::BigPecks]);
+	for _ in 0..8
+	{
+		assert!(species.abilities.contains(&Monster::new(SpeciesType::Pidgey, 5).ability()));
+	}
+
+	let mut monster = Monster::new(SpeciesType::Bulbasaur, 5);
+	assert_eq!(monster.ability(), AbilityType::Overgrow);
+	assert_eq!(monster.ability().ability().name(), "Overgrow");
+	monster.ability_set(AbilityType::Intimidate);
+
+	let mut binary = Vec::new();
+	Monster::save_list_binary(&[monster], &mut binary).unwrap();
+	let loaded = Monster::load_list_binary(&mut &binary[..]).unwrap();
+	assert_eq!(loaded[0].ability(), AbilityType::Intimidate);
+}
+
+// Validate the switch in, after damage and end of turn ability hooks.
+#[test]
+fn ability_hooks()
+{
+	let mut monster_boost = Monster::new(SpeciesType::Mew, 10);
+	monster_boost.ability_set(AbilityType::SpeedBoost);
+	let mut monster_intimidate = Monster::new(SpeciesType::Mew, 10);
+	monster_intimidate.ability_set(AbilityType::Intimidate);
+	let mut monster_stamina = monster_splash(SpeciesType::Mew, 10);
+	monster_stamina.ability_set(AbilityType::Stamina);
+
+	let mut party_data0 = [monster_boost, monster_intimidate];
+	let mut party_data1 = [monster_stamina];
+	let parties = vec![Party::new(&mut party_data0, 0, 1, false),
+		Party::new(&mut party_data1, 1, 1, false)];
+	let mut battle = Battle::with_seed(parties, 3);
+
+	assert_eq!(battle.command_add_attack(0, 0, 0, 1, 0), BattleError::None);
+	assert_eq!(battle.command_add_attack(1, 0, 0, 0, 0), BattleError::None);
+	turn_effects(&mut battle);
+	assert_eq!(battle.state().parties()[0].active_member(0).modifiers().speed_stage(), 1);
+	assert_eq!(battle.state().parties()[1].active_member(0).modifiers().defense_stage(), 1);
+	assert_eq!(battle.state().parties()[1].active_member(0).modifiers().attack_stage(), 0);
+
+	assert_eq!(battle.command_add_switch(0, 0, 1), BattleError::None);
+	assert_eq!(battle.command_add_attack(1, 0, 0, 0, 0), BattleError::None);
+	turn_effects(&mut battle);
+	assert_eq!(battle.state().parties()[1].active_member(0).modifiers().attack_stage(), -1);
+}
+
+// Validate that pinch abilities boost attacks of their element at low health.
+#[test]
+fn ability_pinch()
+{
+	let monster_pinch = Monster::new(SpeciesType::Bulbasaur, 10);
+	assert_eq!(monster_pinch.attacks()[3].attack_type(), AttackType::VineWhip);
+	let monster_skip = monster_splash(SpeciesType::Mew, 10);
+
+	let full = first_damage(monster_pinch.clone(), monster_skip.clone(), 3, 8);
+	let mut monster_low = monster_pinch;
+	let health_lost = monster_low.stat_health() - monster_low.stat_health() / 3;
+	monster_low.health_lose(health_lost);
+	let low = first_damage(monster_low, monster_skip, 3, 8);
+	assert!(low > full);
+	assert!(low <= full * 3 / 2 + 1);
+}
+
+// Validate that applying modifiers, such as from Intimidate, changes every stage by its own amount.
+#[test]
+fn ability_modifiers_apply()
+{
+	let mut modifiers = StatModifiers::default();
+	modifiers.attack_delta(-1);
+	modifiers.defense_delta(2);
+	modifiers.sp_attack_delta(3);
+	modifiers.sp_defense_delta(-2);
+	modifiers.speed_delta(1);
+	modifiers.accuracy_delta(-3);
+	modifiers.evasion_delta(4);
+
+	let mut applied = StatModifiers::default();
+	applied.speed_delta(1);
+	applied.apply(&modifiers);
+	assert_eq!(applied.attack_stage(), -1);
+	assert_eq!(applied.defense_stage(), 2);
+	assert_eq!(applied.sp_attack_stage(), 3);
+	assert_eq!(applied.sp_defense_stage(), -2);
+	assert_eq!(applied.speed_stage(), 2);
+	assert_eq!(applied.accuracy_stage(), -3);
+	assert_eq!(applied.evasion_stage(), 4);
+}
@@ -0,0 +1,49 @@
+extern crate mon_gen;
+
+mod common;
+
+use mon_gen::attack::AttackType;
+use mon_gen::battle::{Battle, BattleError, Party, StatModifiers};
+use mon_gen::monster::MonsterBuilder;
+use mon_gen::species::SpeciesType;
+
+use common::{monster_attacks, turn_effects};
+
+// Validate that accuracy and evasion stages cancel out and that omitted accuracy never misses.
+#[test]
+fn accuracy_modifiers()
+{
+	assert_eq!(AttackType::Splash.attack().accuracy, None);
+	assert_eq!(AttackType::ThunderWave.attack().accuracy, Some(0.9));
+
+	let mut offending = StatModifiers::default();
+	let mut defending = StatModifiers::default();
+	offending.accuracy_delta(1);
+	defending.evasion_delta(2);
+	assert_eq!(offending.accuracy_stage(), 1);
+	assert_eq!(defending.evasion_stage(), 2);
+	assert_eq!(offending.accuracy_value_against(&defending), 0.75);
+	assert_eq!(defending.accuracy_value_against(&offending), 1.0);
+}
+
+// Validate that the hit chance shown before choosing commands follows lowered accuracy.
+#[test]
+fn accuracy_hit_chance()
+{
+	let mut party_data0 = [monster_attacks(&[AttackType::Pound, AttackType::Splash])];
+	let mut party_data1 = [MonsterBuilder::new(SpeciesType::Pidgey, 50)
+		.attacks(&[AttackType::SandAttack])
+		.build()
+		.unwrap()];
+	let parties = vec![Party::new(&mut party_data0, 0, 1, false),
+		Party::new(&mut party_data1, 1, 1, false)];
+	let mut battle = Battle::with_seed(parties, 8);
+	assert_eq!(battle.hit_chance(0, 0, 0, 1, 0), 1.0);
+
+	assert_eq!(battle.command_add_attack(0, 0, 1, 1, 0), BattleError::None);
+	assert_eq!(battle.command_add_attack(1, 0, 0, 0, 0), BattleError::None);
+	turn_effects(&mut battle);
+
+	assert_eq!(battle.hit_chance(0, 0, 0, 1, 0), 0.75);
+	assert_eq!(battle.hit_chance(0, 0, 1, 1, 0), 1.0);
+}
@@ -0,0 +1,77 @@
+extern crate mon_gen;
+
+mod common;
+
+use mon_gen::attack::{Ailment, AttackType};
+use mon_gen::battle::{AilmentCure, AilmentTick, Battle, BattleError, Effect, NoneReason, Party};
+use mon_gen::monster::Monster;
+use mon_gen::species::SpeciesType;
+
+use common::{monster_splash, turn_effects};
+
+// Validate that burn is inflicted, damages at the end of the turn and is saved.
+#[test]
+fn ailment_burn()
+{
+	let mut monster_burn = Monster::new(SpeciesType::Mew, 10);
+	assert_eq!(monster_burn.attack_set(AttackType::WillOWisp, 0), true);
+	let mut party_data0 = [monster_burn];
+	let mut party_data1 = [monster_splash(SpeciesType::Mew, 10)];
+	{
+		let parties = vec!
+		[
+			Party::new(&mut party_data0, 0, 1, false),
+			Party::new(&mut party_data1, 1, 1, false),
+		];
+
+		let mut battle = Battle::with_seed(parties, 3);
+		assert_eq!(battle.command_add_attack(0, 0, 0, 1, 0), BattleError::None);
+		assert_eq!(battle.command_add_attack(1, 0, 0, 0, 0), BattleError::None);
+		turn_effects(&mut battle);
+	}
+
+	let monster = &party_data1[0];
+	assert_eq!(monster.ailment(), Some(Ailment::Burn));
+	assert_eq!(monster.health(), monster.stat_health() - monster.stat_health() / 8);
+
+	let mut text = Vec::new();
+	Monster::save_list_toml(&party_data1, &mut text).unwrap();
+	let monsters = Monster::load_list_toml(&mut &text[..]).unwrap();
+	assert_eq!(monsters[0].ailment(), Some(Ailment::Burn));
+}
+
+// Validate that sleeping members cannot attack until they wake up.
+#[test]
+fn ailment_sleep()
+{
+	let mut monster_sleep = monster_splash(SpeciesType::Mew, 10);
+	monster_sleep.ailment_set(Ailment::Sleep, 1);
+
+	let mut party_data0 = [monster_sleep];
+	let mut party_data1 = [monster_splash(SpeciesType::Mew, 10)];
+	let parties = vec!
+	[
+		Party::new(&mut party_data0, 0, 1, false),
+		Party::new(&mut party_data1, 1, 1, false),
+	];
+
+	let mut battle = Battle::with_seed(parties, 5);
+	let blocked = Effect::None(NoneReason::Ailment(Ailment::Sleep));
+
+	assert_eq!(battle.command_add_attack(0, 0, 0, 1, 0), BattleError::None);
+	assert_eq!(battle.command_add_attack(1, 0, 0, 0, 0), BattleError::None);
+	let effects = turn_effects(&mut battle);
+	assert!(effects.contains(&blocked));
+	assert!(effects.contains(&Effect::AilmentTick(AilmentTick { party: 0, active: 0 })));
+
+	assert_eq!(battle.command_add_attack(0, 0, 0, 1, 0), BattleError::None);
+	assert_eq!(battle.command_add_attack(1, 0, 0, 0, 0), BattleError::None);
+	let effects = turn_effects(&mut battle);
+	assert!(effects.contains(&blocked));
+	assert!(effects.contains(&Effect::AilmentCure(AilmentCure { party: 0, active: 0 })));
+	assert_eq!(battle.state().parties()[0].member(0).ailment(), None);
+
+	assert_eq!(battle.command_add_attack(0, 0, 0, 1, 0), BattleError::None);
+	assert_eq!(battle.command_add_attack(1, 0, 0, 0, 0), BattleError::None);
+	assert!(!turn_effects(&mut battle).contains(&blocked));
+}
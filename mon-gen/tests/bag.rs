@@ -0,0 +1,135 @@
+extern crate mon_gen;
+
+mod common;
+
+use mon_gen::attack::Ailment;
+use mon_gen::battle::{AilmentRemove, Battle, BattleError, BattleExecution, BattleReplay,
+	CommandType, Effect, HealthRestore, ItemUse, LimitRestore, Party};
+use mon_gen::item::ItemType;
+use mon_gen::species::SpeciesType;
+
+use common::{execute, monster_splash, turn_effects};
+
+// Validate that items are used before attacks, restore health and are taken from the bag.
+#[test]
+fn bag_health()
+{
+	let mut monster_hurt = monster_splash(SpeciesType::Mew, 50);
+	monster_hurt.health_lose(30);
+
+	let mut party_data0 = [monster_hurt];
+	let mut party_data1 = [monster_splash(SpeciesType::Mew, 10)];
+	{
+		let mut party0 = Party::new(&mut party_data0, 0, 1, false);
+		party0.bag_add(ItemType::Potion, 2);
+		let parties = vec![party0, Party::new(&mut party_data1, 1, 1, false)];
+		let mut battle = Battle::with_seed(parties, 4);
+		assert_eq!(battle.command_add_attack(1, 0, 0, 0, 0), BattleError::None);
+		assert_eq!(battle.command_add_item(0, 0, ItemType::Potion, 0), BattleError::None);
+
+		let executed = execute(&mut battle);
+		assert_eq!(executed.execution, BattleExecution::Waiting);
+		match executed.commands[0]
+		{
+			CommandType::Item(ref item) => assert_eq!(item.item, ItemType::Potion),
+			_ => panic!("Expected the item to be used before the attack."),
+		}
+		assert!(executed.effects.contains(&Effect::ItemUse(ItemUse
+		{
+			party: 0,
+			item: ItemType::Potion,
+		})));
+		assert!(executed.effects.contains(&Effect::HealthRestore(HealthRestore
+		{
+			party: 0,
+			member: 0,
+			amount: 20,
+		})));
+		assert_eq!(battle.state().parties()[0].bag_count(ItemType::Potion), 1);
+
+		// The bag is stored with the replay so it can be played back.
+		let mut data = Vec::new();
+		battle.replay().save_binary(&mut data).unwrap();
+		let replay = BattleReplay::load_binary(&mut &data[..]).unwrap();
+		assert_eq!(replay.party(0).bag(), &[(ItemType::Potion, 2)]);
+	}
+	assert_eq!(party_data0[0].health(), party_data0[0].stat_health() - 10);
+}
+
+// Validate that items cannot be used without stock, on fainted members, without any effect or
+// outside of battle.
+#[test]
+fn bag_errors()
+{
+	let mut monster_fainted = monster_splash(SpeciesType::Mew, 10);
+	let health = monster_fainted.health();
+	monster_fainted.health_lose(health);
+
+	let mut monster_hurt = monster_splash(SpeciesType::Mew, 10);
+	monster_hurt.health_lose(5);
+
+	let mut party_data0 = [monster_hurt.clone(), monster_hurt, monster_fainted,
+		monster_splash(SpeciesType::Mew, 10)];
+	let mut party_data1 = [monster_splash(SpeciesType::Mew, 10)];
+	let mut party0 = Party::new(&mut party_data0, 0, 2, false);
+	party0.bag_add(ItemType::Potion, 1);
+	party0.bag_add(ItemType::Antidote, 1);
+	let parties = vec![party0, Party::new(&mut party_data1, 1, 1, false)];
+	let mut battle = Battle::with_seed(parties, 8);
+
+	assert_eq!(battle.command_add_item(0, 0, ItemType::Leftovers, 0), BattleError::ItemUnusable);
+	assert_eq!(battle.command_add_item(0, 0, ItemType::SuperPotion, 0), BattleError::ItemEmpty);
+	assert_eq!(battle.command_add_item(0, 0, ItemType::Potion, 2), BattleError::ItemTarget);
+
+	// Items that would have no effect are not used up.
+	assert_eq!(battle.command_add_item(0, 0, ItemType::Potion, 3), BattleError::ItemTarget);
+	assert_eq!(battle.command_add_item(0, 0, ItemType::Antidote, 0), BattleError::ItemTarget);
+	assert_eq!(battle.command_add_item(0, 0, ItemType::Potion, 1), BattleError::None);
+
+	// The only potion is already queued by the other active member.
+	assert_eq!(battle.command_add_item(0, 1, ItemType::Potion, 0), BattleError::ItemEmpty);
+	assert_eq!(battle.command_add_item(0, 0, ItemType::Potion, 0), BattleError::None);
+}
+
+// Validate that items cure ailments and restore attack limits of members that are not active.
+#[test]
+fn bag_restore()
+{
+	let mut monster_benched = monster_splash(SpeciesType::Mew, 10);
+	monster_benched.ailment_set(Ailment::Poison, 0);
+	monster_benched.attacks_mut()[0].limit_left_take(5);
+
+	let mut party_data0 = [monster_splash(SpeciesType::Mew, 10), monster_benched];
+	let mut party_data1 = [monster_splash(SpeciesType::Mew, 10)];
+	{
+		let mut party0 = Party::new(&mut party_data0, 0, 1, false);
+		party0.bag_add(ItemType::FullHeal, 1);
+		party0.bag_add(ItemType::Elixir, 1);
+		let parties = vec![party0, Party::new(&mut party_data1, 1, 1, false)];
+		let mut battle = Battle::with_seed(parties, 2);
+
+		assert_eq!(battle.command_add_item(0, 0, ItemType::FullHeal, 1), BattleError::None);
+		assert_eq!(battle.command_add_attack(1, 0, 0, 0, 0), BattleError::None);
+		let effects = turn_effects(&mut battle);
+		assert!(effects.contains(&Effect::AilmentRemove(AilmentRemove
+		{
+			party: 0,
+			member: 1,
+		})));
+
+		assert_eq!(battle.command_add_item(0, 0, ItemType::Elixir, 1), BattleError::None);
+		assert_eq!(battle.command_add_attack(1, 0, 0, 0, 0), BattleError::None);
+		let effects = turn_effects(&mut battle);
+		assert!(effects.contains(&Effect::LimitRestore(LimitRestore
+		{
+			party: 0,
+			member: 1,
+			attack: 0,
+			amount: 5,
+		})));
+		assert!(battle.state().parties()[0].bag().is_empty());
+	}
+	let monster = &party_data0[1];
+	assert_eq!(monster.ailment(), None);
+	assert_eq!(monster.attacks()[0].limit_left(), monster.attacks()[0].limit_max());
+}
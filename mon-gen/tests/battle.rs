@@ -1,6 +1,6 @@
 extern crate mon_gen;
 
-use mon_gen::battle::{Battle, BattleError, Party};
+use mon_gen::battle::{Battle, BattleError, BattleExecution, Effect, Party};
 use mon_gen::monster::{Monster, StatType};
 use mon_gen::species::SpeciesType;
 
@@ -34,7 +34,7 @@ fn battle_command_error()
 	let parties = vec!
 	[
 		Party::new(&mut party_data0, 0, 3, false),
-		Party::new(&mut party_data1, 0, 3, false),
+		Party::new(&mut party_data1, 1, 3, false),
 	];
 
 	let mut battle = Battle::new(parties).unwrap();
@@ -78,3 +78,52 @@ fn battle_command_error()
 	// assert_eq!(battle.command_add_switch(0, 0, 0), BattleError::Rejected);
 	// assert_eq!(battle.command_add_attack(0, 0, 0, 1, 0), BattleError::Rejected);
 }
+
+// Runs two turns of both parties attacking each other and returns every damage roll.
+fn battle_seed_damage(monster0: &Monster, monster1: &Monster, seed: usize) -> Vec<(StatType, bool)>
+{
+	let mut party_data0 = [monster0.clone()];
+	let mut party_data1 = [monster1.clone()];
+
+	let parties = vec!
+	[
+		Party::new(&mut party_data0, 0, 1, false),
+		Party::new(&mut party_data1, 1, 1, false),
+	];
+
+	let mut battle = Battle::with_seed(parties, seed);
+	let mut damage = Vec::new();
+	for _ in 0..2
+	{
+		assert_eq!(battle.command_add_attack(0, 0, 0, 1, 0), BattleError::None);
+		assert_eq!(battle.command_add_attack(1, 0, 0, 0, 0), BattleError::None);
+		loop
+		{
+			match battle.execute()
+			{
+				BattleExecution::Waiting => break,
+				BattleExecution::Effect =>
+				{
+					if let Effect::Damage(ref effect) = *battle.current_effect()
+					{
+						damage.push((effect.amount(), effect.critical()));
+					}
+				}
+				_ => {}
+			}
+		}
+	}
+	damage
+}
+
+// Validate that battles with the same seed produce the same results.
+#[test]
+fn battle_seed()
+{
+	let monster0 = Monster::new(SpeciesType::Mew, 100);
+	let monster1 = Monster::new(SpeciesType::Mew, 100);
+
+	let damage = battle_seed_damage(&monster0, &monster1, 42);
+	assert_eq!(damage.len(), 4);
+	assert_eq!(damage, battle_seed_damage(&monster0, &monster1, 42));
+}
@@ -0,0 +1,80 @@
+extern crate mon_gen;
+
+use mon_gen::ability::AbilityType;
+use mon_gen::attack::AttackType;
+use mon_gen::item::ItemType;
+use mon_gen::monster::{MonsterBuilder, MonsterBuildError, Nature};
+use mon_gen::species::{Gender, SpeciesType};
+
+// Validate that chosen values are used instead of random ones.
+#[test]
+fn builder_values()
+{
+	let monster = MonsterBuilder::new(SpeciesType::Bulbasaur, 10)
+		.nick("Bulby")
+		.personality(1234)
+		.gender(Gender::Female)
+		.nature(Nature::Modest)
+		.ability(AbilityType::Chlorophyll)
+		.individuals([31, 30, 29, 28, 27, 26])
+		.efforts([0, 0, 0, 252, 0, 4])
+		.attacks(&[AttackType::VineWhip, AttackType::Tackle])
+		.held_item(ItemType::MiracleSeed)
+		.build()
+		.unwrap();
+
+	assert_eq!(monster.nick(), "Bulby");
+	assert_eq!(monster.level(), 10);
+	assert_eq!(monster.personality(), 1234);
+	assert_eq!(monster.gender(), Gender::Female);
+	assert_eq!(monster.nature(), Nature::Modest);
+	assert_eq!(monster.ability(), AbilityType::Chlorophyll);
+	assert_eq!(monster.individual_health(), 31);
+	assert_eq!(monster.individual_speed(), 26);
+	assert_eq!(monster.yield_spattack(), 252);
+	assert_eq!(monster.yield_speed(), 4);
+	assert_eq!(monster.attacks().len(), 2);
+	assert_eq!(monster.attacks()[0].attack_type(), AttackType::VineWhip);
+	assert_eq!(monster.attacks()[1].attack_type(), AttackType::Tackle);
+	assert_eq!(monster.held_item(), Some(ItemType::MiracleSeed));
+	assert_eq!(monster.health(), monster.stat_health());
+
+	// Attacks that are not chosen are the ones learnable at the level.
+	let monster = MonsterBuilder::new(SpeciesType::Bulbasaur, 3).seed(7).build().unwrap();
+	assert_eq!(monster.attacks()[0].attack_type(), AttackType::Tackle);
+	assert_eq!(monster.attacks()[1].attack_type(), AttackType::Growl);
+}
+
+// Validate that the same seed creates the same monster.
+#[test]
+fn builder_seed()
+{
+	let builder = MonsterBuilder::new(SpeciesType::Pidgey, 20).seed(42);
+	let monster = builder.build().unwrap();
+	assert_eq!(format!("{:?}", builder.build().unwrap()), format!("{:?}", monster));
+}
+
+// Validate that values which are invalid for the species fail with an error.
+#[test]
+fn builder_errors()
+{
+	let build = |builder: MonsterBuilder| builder.build().unwrap_err();
+	let bulbasaur = MonsterBuilder::new(SpeciesType::Bulbasaur, 10);
+
+	assert_eq!(build(MonsterBuilder::new(SpeciesType::Bulbasaur, 0)),
+		MonsterBuildError::InvalidLevel(0));
+	assert_eq!(build(bulbasaur.clone().form(1)), MonsterBuildError::InvalidForm(1));
+	assert_eq!(build(bulbasaur.clone().nick("Bul\0by")), MonsterBuildError::InvalidNick);
+	assert_eq!(build(MonsterBuilder::new(SpeciesType::Mew, 10).gender(Gender::Male)),
+		MonsterBuildError::InvalidGender(Gender::Male));
+	assert_eq!(build(bulbasaur.clone().ability(AbilityType::Blaze)),
+		MonsterBuildError::InvalidAbility(AbilityType::Blaze));
+	assert_eq!(build(bulbasaur.clone().individuals([0, 0, 32, 0, 0, 0])),
+		MonsterBuildError::InvalidIndividual);
+	assert_eq!(build(bulbasaur.clone().efforts([252, 252, 7, 0, 0, 0])),
+		MonsterBuildError::InvalidEffort);
+	assert_eq!(build(bulbasaur.clone().attacks(&[AttackType::Tackle, AttackType::Tackle])),
+		MonsterBuildError::InvalidAttackCount);
+	assert_eq!(build(bulbasaur.clone().attacks(&[AttackType::Splash])),
+		MonsterBuildError::InvalidAttack(AttackType::Splash));
+}
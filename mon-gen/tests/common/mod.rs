@@ -0,0 +1,131 @@
+// Helpers shared between integration tests. Not every test uses every helper.
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+
+use mon_gen::attack::AttackType;
+use mon_gen::battle::{Battle, BattleError, BattleExecution, BattleOutcome, CommandType, Effect,
+	Party};
+use mon_gen::monster::{Monster, MonsterBuilder};
+use mon_gen::species::SpeciesType;
+
+// Everything that was executed until a battle stopped.
+pub struct Executed
+{
+	pub commands: Vec<CommandType>,
+	pub effects: Vec<Effect>,
+	// The execution that stopped the battle, such as waiting for input or finishing.
+	pub execution: BattleExecution,
+}
+
+// Executes the battle until it stops for input or is finished.
+pub fn execute(battle: &mut Battle) -> Executed
+{
+	let mut commands = Vec::new();
+	let mut effects = Vec::new();
+	loop
+	{
+		match battle.execute()
+		{
+			BattleExecution::Command => commands.push(battle.current_command().clone()),
+			BattleExecution::Effect | BattleExecution::Death(_) =>
+			{
+				effects.push(battle.current_effect().clone());
+			}
+			execution =>
+			{
+				return Executed
+				{
+					commands: commands,
+					effects: effects,
+					execution: execution,
+				};
+			}
+		}
+	}
+}
+
+// Executes the current turn and returns every effect that was executed.
+pub fn turn_effects(battle: &mut Battle) -> Vec<Effect>
+{
+	let executed = execute(battle);
+	assert_eq!(executed.execution, BattleExecution::Waiting);
+	executed.effects
+}
+
+// Executes the battle until it finishes with the given outcome and returns every effect that was
+// executed.
+pub fn finish_effects(battle: &mut Battle, outcome: BattleOutcome) -> Vec<Effect>
+{
+	let executed = execute(battle);
+	assert_eq!(executed.execution, BattleExecution::Finished(outcome));
+	executed.effects
+}
+
+// Attacks the member of the second party with the given attack of the member of the first party
+// and returns the damage dealt by the first damage effect of the turn.
+pub fn first_damage(monster0: Monster, monster1: Monster, attack: usize, seed: usize) -> u16
+{
+	let mut party_data0 = [monster0];
+	let mut party_data1 = [monster1];
+	let parties = vec![Party::new(&mut party_data0, 0, 1, false),
+		Party::new(&mut party_data1, 1, 1, false)];
+	let mut battle = Battle::with_seed(parties, seed);
+	assert_eq!(battle.command_add_attack(0, 0, attack, 1, 0), BattleError::None);
+	assert_eq!(battle.command_add_attack(1, 0, 0, 0, 0), BattleError::None);
+	for effect in turn_effects(&mut battle)
+	{
+		if let Effect::Damage(ref damage) = effect
+		{
+			return damage.amount();
+		}
+	}
+	panic!("Expected the attack to deal damage.");
+}
+
+// Defeats the weak active member of the second party with every active member of the first party
+// and returns the experience gained by each member of the first party.
+pub fn experience_gains(parties: Vec<Party>) -> HashMap<usize, u32>
+{
+	let active_count = parties[0].active_count();
+	let mut battle = Battle::with_seed(parties, 3);
+	for active in 0..active_count
+	{
+		assert_eq!(battle.command_add_attack(0, active, 0, 1, 0), BattleError::None);
+	}
+	assert_eq!(battle.command_add_attack(1, 0, 0, 0, 0), BattleError::None);
+
+	let mut gains = HashMap::new();
+	for effect in finish_effects(&mut battle, BattleOutcome::Win(0))
+	{
+		if let Effect::ExperienceGain(ref gain) = effect
+		{
+			assert_eq!(gain.party, 0);
+			gains.insert(gain.member, gain.amount);
+		}
+	}
+	gains
+}
+
+// A level 2 Pidgey with a single point of health left, which faints from any attack.
+pub fn monster_weak() -> Monster
+{
+	let mut monster = Monster::new(SpeciesType::Pidgey, 2);
+	let health = monster.health();
+	monster.health_lose(health - 1);
+	monster
+}
+
+// A monster of the given species whose first attack does nothing.
+pub fn monster_splash(species: SpeciesType, level: u8) -> Monster
+{
+	let mut monster = Monster::new(species, level);
+	assert_eq!(monster.attack_set(AttackType::Splash, 0), true);
+	monster
+}
+
+// A level 50 Mew that only knows the given attacks.
+pub fn monster_attacks(attacks: &[AttackType]) -> Monster
+{
+	MonsterBuilder::new(SpeciesType::Mew, 50).attacks(attacks).build().unwrap()
+}
@@ -1,10 +1,13 @@
 extern crate mon_gen;
 
+mod common;
+
 use mon_gen::attack::AttackType;
 use mon_gen::battle::
 {
 	Battle,
 	BattleExecution,
+	BattleOutcome,
 	BattlePartyMember,
 	CommandAttack,
 	CommandType,
@@ -17,6 +20,8 @@ use mon_gen::battle::
 use mon_gen::monster::Monster;
 use mon_gen::species::SpeciesType;
 
+use common::monster_weak;
+
 // Validate move effectiveness changes battle damage and fixed damage attacks.
 #[test]
 fn battle_damage()
@@ -159,9 +164,49 @@ fn battle_damage_knock_out()
 		member: 0,
 	}));
 
-	assert_eq!(battle.execute(), BattleExecution::Finished(0));
+	assert_eq!(battle.execute(), BattleExecution::Finished(BattleOutcome::Win(0)));
 
 	// TODO: Check still works against type disadvantage (Fire species).
 
 	// TODO: Check does not work against types that are unaffective (Flying species).
 }
+
+// Attackers that faint from their own attack win if they defeat the last enemy at the same time.
+#[test]
+fn battle_damage_self_faint()
+{
+	let mut monster_explosion = Monster::new(SpeciesType::Mew, 50);
+	assert_eq!(monster_explosion.attack_set(AttackType::Explosion, 0), true);
+	let mut party_data0 =
+	[
+		monster_explosion,
+	];
+
+	let mut party_data1 =
+	[
+		monster_weak(),
+	];
+
+	let parties = vec!
+	[
+		Party::new(&mut party_data0, 0, 1, false),
+		Party::new(&mut party_data1, 1, 1, false),
+	];
+
+	let mut battle = Battle::with_seed(parties, 1);
+	battle.command_add_attack(0, 0, 0, 1, 0);
+	battle.command_add_attack(1, 0, 0, 0, 0);
+
+	assert_eq!(battle.execute(), BattleExecution::Command);
+	assert_eq!(battle.execute(), BattleExecution::Death(BattlePartyMember
+	{
+		party: 1,
+		member: 0,
+	}));
+	assert_eq!(battle.execute(), BattleExecution::Death(BattlePartyMember
+	{
+		party: 0,
+		member: 0,
+	}));
+	assert_eq!(battle.execute(), BattleExecution::Finished(BattleOutcome::Win(0)));
+}
@@ -0,0 +1,83 @@
+extern crate mon_gen;
+
+mod common;
+
+use mon_gen::battle::{Battle, BattleError, BattleOutcome, Effect, EffortGain, Party};
+use mon_gen::monster::Monster;
+use mon_gen::species::SpeciesType;
+
+use common::{finish_effects, monster_weak};
+
+// Validate that effort values stop increasing at the per statistic and total limits.
+#[test]
+fn effort_limits()
+{
+	let mut monster = Monster::new(SpeciesType::Mew, 50);
+	let stat_speed = monster.stat_speed();
+
+	assert_eq!(monster.efforts_add(&[0, 0, 0, 0, 0, 250]), [0, 0, 0, 0, 0, 250]);
+	assert!(monster.stat_speed() > stat_speed);
+	assert_eq!(monster.health(), monster.stat_health());
+
+	assert_eq!(monster.efforts_add(&[0, 0, 0, 0, 0, 10]), [0, 0, 0, 0, 0, 2]);
+	assert_eq!(monster.efforts_add(&[255, 255, 255, 0, 0, 0]), [252, 6, 0, 0, 0, 0]);
+	assert_eq!(monster.yield_health(), 252);
+	assert_eq!(monster.yield_attack(), 6);
+	assert_eq!(monster.yield_speed(), 252);
+}
+
+// Defeats a weak member with the given member and returns every effect that was executed.
+fn effort_effects(monster: Monster) -> (Vec<Effect>, Monster)
+{
+	let mut party_data0 = [monster];
+	let mut party_data1 = [monster_weak()];
+	let effects =
+	{
+		let parties = vec![Party::new(&mut party_data0, 0, 1, true),
+			Party::new(&mut party_data1, 1, 1, false)];
+		let mut battle = Battle::with_seed(parties, 3);
+		assert_eq!(battle.command_add_attack(0, 0, 0, 1, 0), BattleError::None);
+		assert_eq!(battle.command_add_attack(1, 0, 0, 0, 0), BattleError::None);
+		finish_effects(&mut battle, BattleOutcome::Win(0))
+	};
+	(effects, party_data0[0].clone())
+}
+
+// Validate that defeating a member yields its species' effort values before experience, and
+// that members that reached the limits are not reported as gaining any.
+#[test]
+fn effort_battle()
+{
+	let (effects, monster) = effort_effects(Monster::new(SpeciesType::Bulbasaur, 10));
+	let effort_index = effects.iter().position(|effect|
+	{
+		*effect == Effect::EffortGain(EffortGain
+		{
+			party: 0,
+			member: 0,
+			efforts: [0, 0, 0, 0, 0, 1],
+		})
+	})
+	.expect("Expected effort values to be gained.");
+	match effects[effort_index + 1]
+	{
+		Effect::ExperienceGain(_) => {}
+		_ => panic!("Expected experience to be gained after effort values."),
+	}
+	assert_eq!(monster.yield_speed(), 1);
+
+	let mut monster_capped = Monster::new(SpeciesType::Bulbasaur, 10);
+	monster_capped.efforts_add(&[252, 252, 6, 0, 0, 0]);
+	let (effects, monster) = effort_effects(monster_capped);
+	assert!(!effects.iter().any(|effect| match *effect
+	{
+		Effect::EffortGain(_) => true,
+		_ => false,
+	}));
+	assert!(effects.iter().any(|effect| match *effect
+	{
+		Effect::ExperienceGain(_) => true,
+		_ => false,
+	}));
+	assert_eq!(monster.yield_speed(), 0);
+}
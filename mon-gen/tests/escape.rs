@@ -0,0 +1,72 @@
+extern crate mon_gen;
+
+mod common;
+
+use mon_gen::attack::AttackType;
+use mon_gen::battle::{Battle, BattleError, BattleExecution, BattleOutcome, Effect, EscapeFailure,
+	EscapeSuccess, Party, escape_chance};
+use mon_gen::monster::{Monster, MonsterBuilder};
+use mon_gen::species::SpeciesType;
+
+use common::execute;
+
+// Validate that faster parties always escape and that slower ones get likelier to with attempts.
+#[test]
+fn escape_odds()
+{
+	assert_eq!(escape_chance(100, 100, 0), 1.0);
+	assert_eq!(escape_chance(120, 100, 0), 1.0);
+	assert_eq!(escape_chance(50, 100, 0), 0.25);
+	assert!(escape_chance(50, 100, 1) > escape_chance(50, 100, 0));
+	assert_eq!(escape_chance(0, 100, 0), 0.0);
+	assert_eq!(escape_chance(50, 100, 8), 1.0);
+}
+
+// Validate that failed escape attempts are counted and that escaping ends the battle.
+#[test]
+fn escape_battle()
+{
+	let mut party_data0 = [Monster::new(SpeciesType::Bulbasaur, 5)];
+	let mut party_data1 = [MonsterBuilder::new(SpeciesType::Mew, 100)
+		.attacks(&[AttackType::Splash])
+		.build()
+		.unwrap()];
+	let parties = vec![Party::new(&mut party_data0, 0, 1, false),
+		Party::new(&mut party_data1, 1, 1, false)];
+	let mut battle = Battle::with_seed(parties, 3);
+
+	let mut failures = 0;
+	loop
+	{
+		assert_eq!(battle.command_add_escape(0), BattleError::None);
+		assert_eq!(battle.command_add_attack(1, 0, 0, 0, 0), BattleError::None);
+
+		let mut escaped = false;
+		let executed = execute(&mut battle);
+		for effect in &executed.effects
+		{
+			match *effect
+			{
+				Effect::EscapeFailure(ref failure) =>
+				{
+					assert_eq!(*failure, EscapeFailure { party: 0 });
+					failures += 1;
+				}
+				Effect::EscapeSuccess(ref success) =>
+				{
+					assert_eq!(*success, EscapeSuccess { party: 0 });
+					escaped = true;
+				}
+				_ => {}
+			}
+		}
+		assert_eq!(battle.state().parties()[0].escape_attempts(), failures);
+		if escaped
+		{
+			assert_eq!(executed.execution, BattleExecution::Finished(BattleOutcome::Escape(0)));
+			break;
+		}
+		assert_eq!(executed.execution, BattleExecution::Waiting);
+	}
+	assert!(failures > 0);
+}
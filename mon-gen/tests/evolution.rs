@@ -0,0 +1,78 @@
+extern crate mon_gen;
+
+use std::ffi::CString;
+
+use mon_gen::attack::AttackType;
+use mon_gen::item::ItemType;
+use mon_gen::monster::Monster;
+use mon_gen::species::SpeciesType;
+
+// Validate that levelling up makes an evolution pending and that evolving keeps other values.
+#[test]
+fn evolution_level()
+{
+	let mut monster = Monster::new(SpeciesType::Bulbasaur, 15);
+	monster.health_lose(4);
+	assert_eq!(monster.evolution_pending(), None);
+
+	let growth = SpeciesType::Bulbasaur.species().growth;
+	let experience = growth.experience_with_level(16) - monster.experience_total();
+	monster.experience_add(experience);
+	assert_eq!(monster.evolution_pending(), Some(SpeciesType::Ivysaur));
+
+	let individuals = [monster.individual_health(), monster.individual_attack(),
+		monster.individual_speed()];
+	let stat_health = monster.stat_health();
+	monster.evolve(SpeciesType::Ivysaur);
+
+	assert_eq!(monster.species(), SpeciesType::Ivysaur);
+	assert_eq!(monster.nick(), "Ivysaur");
+	assert_eq!(monster.level(), 16);
+	assert_eq!(individuals, [monster.individual_health(), monster.individual_attack(),
+		monster.individual_speed()]);
+	assert!(monster.stat_health() > stat_health);
+	assert_eq!(monster.health(), monster.stat_health() - 4);
+	assert_eq!(monster.evolution_pending(), None);
+}
+
+// Validate that item and friendship evolutions only happen once their trigger is met.
+#[test]
+fn evolution_triggers()
+{
+	let mut pichu = Monster::new(SpeciesType::Pichu, 5);
+	pichu.nick_raw_set(CString::new("Sparky").unwrap());
+	assert_eq!(pichu.evolution_pending(), None);
+	assert_eq!(pichu.evolution_trade(), None);
+
+	pichu.friendship_set(220);
+	assert_eq!(pichu.evolution_pending(), Some(SpeciesType::Pikachu));
+	pichu.evolve(SpeciesType::Pikachu);
+	assert_eq!(pichu.nick(), "Sparky");
+	assert_eq!(pichu.friendship(), 220);
+
+	assert_eq!(pichu.evolution_pending(), None);
+	assert_eq!(pichu.evolution_item(ItemType::Potion), None);
+	assert_eq!(pichu.evolution_item(ItemType::ThunderStone), Some(SpeciesType::Raichu));
+}
+
+// Validate that evolved members keep attacks the new species cannot learn and still load.
+#[test]
+fn evolution_save_load()
+{
+	let mut monster = Monster::new(SpeciesType::Pikachu, 10);
+	assert!(monster.attacks().iter().any(|attack| attack.attack_type() == AttackType::ThunderWave));
+	let evolved = monster.evolution_item(ItemType::ThunderStone).unwrap();
+	monster.evolve(evolved);
+	assert_eq!(monster.species(), SpeciesType::Raichu);
+	assert!(!SpeciesType::Raichu.species().attack_valid(AttackType::ThunderWave));
+
+	let mut text = Vec::new();
+	Monster::save_list_toml(&[monster.clone()], &mut text).unwrap();
+	let loaded = Monster::load_list_toml(&mut &text[..]).unwrap();
+	assert_eq!(format!("{:?}", loaded[0]), format!("{:?}", monster));
+
+	let mut binary = Vec::new();
+	Monster::save_list_binary(&[monster.clone()], &mut binary).unwrap();
+	let loaded = Monster::load_list_binary(&mut &binary[..]).unwrap();
+	assert_eq!(format!("{:?}", loaded[0]), format!("{:?}", monster));
+}
@@ -0,0 +1,52 @@
+extern crate mon_gen;
+
+mod common;
+
+use std::collections::HashMap;
+
+use mon_gen::battle::{ExperienceShare, Party};
+use mon_gen::monster::{Monster, StatType};
+use mon_gen::species::SpeciesType;
+
+use common::{experience_gains, monster_weak};
+
+// Defeats a weak member with two active members and returns the experience gained by each member.
+fn experience_share_gains(share: ExperienceShare) -> HashMap<usize, u32>
+{
+	let mut monster_fainted = Monster::new(SpeciesType::Bulbasaur, 10);
+	monster_fainted.health_lose(StatType::max_value());
+
+	let mut party_data0 = [Monster::new(SpeciesType::Bulbasaur, 10),
+		Monster::new(SpeciesType::Bulbasaur, 10), Monster::new(SpeciesType::Bulbasaur, 10),
+		monster_fainted];
+	let mut party_data1 = [monster_weak()];
+	let mut party0 = Party::new(&mut party_data0, 0, 2, true);
+	party0.experience_share_set(share);
+	assert_eq!(party0.experience_share(), share);
+	experience_gains(vec![party0, Party::new(&mut party_data1, 1, 1, false)])
+}
+
+// Validate that each experience share policy distributes experience to the expected members.
+#[test]
+fn experience_share()
+{
+	let yield_base = SpeciesType::Pidgey.species().experience_yield as f32;
+	let gain = |fraction: f32| (yield_base * 2f32 * fraction / 7f32).round() as u32;
+
+	let gains = experience_share_gains(ExperienceShare::Exposed);
+	assert_eq!(gains.len(), 2);
+	assert_eq!(gains[&0], gain(1f32));
+	assert_eq!(gains[&1], gain(1f32));
+
+	let gains = experience_share_gains(ExperienceShare::Split);
+	assert_eq!(gains.len(), 2);
+	assert_eq!(gains[&0], gain(0.5f32));
+	assert_eq!(gains[&1], gain(0.5f32));
+
+	// Members without health do not gain a share.
+	let gains = experience_share_gains(ExperienceShare::Share);
+	assert_eq!(gains.len(), 3);
+	assert_eq!(gains[&0], gain(1f32));
+	assert_eq!(gains[&1], gain(1f32));
+	assert_eq!(gains[&2], gain(0.5f32));
+}
@@ -0,0 +1,80 @@
+extern crate mon_gen;
+
+mod common;
+
+use mon_gen::attack::AttackType;
+use mon_gen::battle::{Battle, BattleError, BattleReplay, Effect, FieldLayout, Party};
+use mon_gen::monster::Monster;
+
+use common::{monster_attacks, turn_effects};
+
+fn monster_field() -> Monster
+{
+	monster_attacks(&[AttackType::Pound, AttackType::Surf, AttackType::Splash])
+}
+
+// Validate that members on the far ends of a triple battle cannot reach each other.
+#[test]
+fn field_triple()
+{
+	let mut party_data0 = [monster_field(), monster_field(), monster_field()];
+	let mut party_data1 = [monster_field(), monster_field(), monster_field()];
+	let parties = vec![Party::new(&mut party_data0, 0, 3, false),
+		Party::new(&mut party_data1, 1, 3, false)];
+	let mut battle = Battle::with_seed(parties, 4);
+	assert_eq!(battle.state().layout(), FieldLayout::Triple);
+
+	// Members on one end cannot reach enemies on the other end.
+	assert_eq!(battle.command_add_attack(0, 0, 0, 1, 2), BattleError::AttackTarget);
+	assert_eq!(battle.command_add_attack(0, 2, 0, 1, 0), BattleError::AttackTarget);
+	assert_eq!(battle.command_add_attack(0, 1, 0, 1, 0), BattleError::None);
+	assert_eq!(battle.command_add_attack(0, 1, 0, 1, 2), BattleError::None);
+
+	assert_eq!(battle.command_add_attack(0, 0, 1, 1, 1), BattleError::None);
+	assert_eq!(battle.command_add_attack(0, 1, 2, 1, 1), BattleError::None);
+	assert_eq!(battle.command_add_attack(0, 2, 2, 1, 1), BattleError::None);
+	for active in 0..3
+	{
+		assert_eq!(battle.command_add_attack(1, active, 2, 0, 1), BattleError::None);
+	}
+
+	// Surf only hits members that are adjacent to the user.
+	let mut targets = Vec::new();
+	for effect in turn_effects(&mut battle)
+	{
+		if let Effect::Damage(ref damage) = effect
+		{
+			targets.push((damage.party, damage.active));
+		}
+	}
+	targets.sort();
+	assert_eq!(targets, vec![(0, 1), (1, 0), (1, 1)]);
+}
+
+// Validate that a shrunk side moves to the center and that the layout can be chosen.
+#[test]
+fn field_layout()
+{
+	let mut party_data0 = [monster_field(), monster_field(), monster_field()];
+	let mut party_data1 = [monster_field()];
+	let parties = vec![Party::new(&mut party_data0, 0, 3, false),
+		Party::new(&mut party_data1, 1, 3, false)];
+	let mut battle = Battle::with_seed(parties, 4);
+	assert_eq!(battle.state().layout(), FieldLayout::Triple);
+	assert_eq!(battle.state().field_position(1, 0), 1);
+
+	// Only the members in front of a rotation battle can reach anyone.
+	assert_eq!(battle.layout_set(FieldLayout::Rotation), BattleError::None);
+	assert_eq!(battle.state().field_position(1, 0), 0);
+	assert_eq!(battle.command_add_attack(0, 1, 0, 1, 0), BattleError::AttackTarget);
+	assert_eq!(battle.command_add_attack(0, 0, 0, 1, 0), BattleError::None);
+
+	// The layout cannot change once commands were added.
+	assert_eq!(battle.layout_set(FieldLayout::Triple), BattleError::Rejected);
+	assert_eq!(battle.state().layout(), FieldLayout::Rotation);
+
+	let mut text = Vec::new();
+	battle.replay().save_toml(&mut text).unwrap();
+	let replay = BattleReplay::load_toml(&mut &text[..]).unwrap();
+	assert_eq!(replay.layout(), Some(FieldLayout::Rotation));
+}
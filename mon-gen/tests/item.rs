@@ -0,0 +1,111 @@
+extern crate mon_gen;
+
+mod common;
+
+use mon_gen::attack::{Ailment, AttackType};
+use mon_gen::battle::{Battle, BattleError, BattleOutcome, Effect, Heal, ItemConsume, Party};
+use mon_gen::item::ItemType;
+use mon_gen::monster::Monster;
+use mon_gen::species::SpeciesType;
+
+use common::{finish_effects, first_damage, monster_splash, turn_effects};
+
+// Validate that berries take effect once the holder is low on health and are used up.
+#[test]
+fn item_berry()
+{
+	let mut monster_berry = monster_splash(SpeciesType::Mew, 50);
+	monster_berry.held_item_set(ItemType::OranBerry);
+	let health_lost = monster_berry.stat_health() - monster_berry.stat_health() / 2 - 1;
+	monster_berry.health_lose(health_lost);
+
+	let mut party_data0 = [monster_berry];
+	let mut party_data1 = [Monster::new(SpeciesType::Mew, 10)];
+	{
+		let parties = vec![Party::new(&mut party_data0, 0, 1, false),
+			Party::new(&mut party_data1, 1, 1, false)];
+		let mut battle = Battle::with_seed(parties, 5);
+		assert_eq!(battle.command_add_attack(0, 0, 0, 1, 0), BattleError::None);
+		assert_eq!(battle.command_add_attack(1, 0, 0, 0, 0), BattleError::None);
+		let effects = turn_effects(&mut battle);
+		assert!(effects.contains(&Effect::Heal(Heal
+		{
+			party: 0,
+			active: 0,
+			amount: 10,
+		})));
+		assert!(effects.contains(&Effect::ItemConsume(ItemConsume
+		{
+			party: 0,
+			active: 0,
+			item: ItemType::OranBerry,
+		})));
+	}
+	assert_eq!(party_data0[0].held_item(), None);
+}
+
+// Validate that leftovers restore health every turn and are saved with the holder.
+#[test]
+fn item_leftovers()
+{
+	let mut monster_leftovers = monster_splash(SpeciesType::Mew, 50);
+	monster_leftovers.held_item_set(ItemType::Leftovers);
+	monster_leftovers.health_lose(20);
+
+	let mut party_data0 = [monster_leftovers];
+	let mut party_data1 = [monster_splash(SpeciesType::Mew, 10)];
+	{
+		let parties = vec![Party::new(&mut party_data0, 0, 1, false),
+			Party::new(&mut party_data1, 1, 1, false)];
+		let mut battle = Battle::with_seed(parties, 7);
+		assert_eq!(battle.command_add_attack(0, 0, 0, 1, 0), BattleError::None);
+		assert_eq!(battle.command_add_attack(1, 0, 0, 0, 0), BattleError::None);
+		turn_effects(&mut battle);
+	}
+	let monster = &party_data0[0];
+	assert_eq!(monster.health(), monster.stat_health() - 20 + monster.stat_health() / 16);
+
+	let mut text = Vec::new();
+	Monster::save_list_toml(&party_data0, &mut text).unwrap();
+	let loaded = Monster::load_list_toml(&mut &text[..]).unwrap();
+	assert_eq!(loaded[0].held_item(), Some(ItemType::Leftovers));
+}
+
+// Validate that leftovers do not bring back a holder that fainted earlier in the turn.
+#[test]
+fn item_leftovers_fainted()
+{
+	let mut monster_leftovers = monster_splash(SpeciesType::Mew, 10);
+	monster_leftovers.held_item_set(ItemType::Leftovers);
+	monster_leftovers.ailment_set(Ailment::Burn, 0);
+	let health = monster_leftovers.health();
+	monster_leftovers.health_lose(health - 1);
+
+	let mut party_data0 = [monster_leftovers];
+	let mut party_data1 = [monster_splash(SpeciesType::Mew, 10)];
+	{
+		let parties = vec![Party::new(&mut party_data0, 0, 1, false),
+			Party::new(&mut party_data1, 1, 1, false)];
+		let mut battle = Battle::with_seed(parties, 7);
+		assert_eq!(battle.command_add_attack(0, 0, 0, 1, 0), BattleError::None);
+		assert_eq!(battle.command_add_attack(1, 0, 0, 0, 0), BattleError::None);
+		finish_effects(&mut battle, BattleOutcome::Win(1));
+	}
+	assert_eq!(party_data0[0].health(), 0);
+}
+
+// Validate that held items boost attacks of their element.
+#[test]
+fn item_boost()
+{
+	let monster_boost = Monster::new(SpeciesType::Bulbasaur, 10);
+	assert_eq!(monster_boost.attacks()[3].attack_type(), AttackType::VineWhip);
+	let monster_skip = monster_splash(SpeciesType::Mew, 10);
+
+	let normal = first_damage(monster_boost.clone(), monster_skip.clone(), 3, 9);
+	let mut monster_seed = monster_boost;
+	monster_seed.held_item_set(ItemType::MiracleSeed);
+	let boosted = first_damage(monster_seed, monster_skip, 3, 9);
+	assert!(boosted > normal);
+	assert!(boosted <= normal * 6 / 5 + 1);
+}
@@ -0,0 +1,125 @@
+extern crate mon_gen;
+
+mod common;
+
+use mon_gen::attack::AttackType;
+use mon_gen::battle::{Battle, BattleError, BattleOutcome, BattleReplay, BattleReplayPlayer, Effect,
+	Party};
+use mon_gen::monster::Monster;
+use mon_gen::species::SpeciesType;
+
+use common::{finish_effects, monster_splash, monster_weak, turn_effects};
+
+// Validate that levelling up recalculates statistics and lists the attacks learnable on the way.
+#[test]
+fn level_up_attacks()
+{
+	let mut monster = Monster::new(SpeciesType::Bulbasaur, 6);
+	let stat_health = monster.stat_health();
+
+	let growth = SpeciesType::Bulbasaur.species().growth;
+	let experience = growth.experience_with_level(9) - monster.experience_total();
+	let level_up = monster.experience_add(experience);
+
+	assert_eq!(level_up.level_from, 6);
+	assert_eq!(level_up.level, 9);
+	assert_eq!(level_up.levels(), 3);
+	assert_eq!(level_up.stats[0], monster.stat_health());
+	assert_eq!(level_up.stats[5], monster.stat_speed());
+	assert_eq!(level_up.attacks, vec![(7, AttackType::LeechSeed), (9, AttackType::VineWhip)]);
+	assert!(monster.stat_health() > stat_health);
+	assert_eq!(monster.health(), monster.stat_health());
+
+	assert_eq!(monster.attack_learn(AttackType::LeechSeed, None), true);
+	assert_eq!(monster.attack_learn(AttackType::LeechSeed, None), false);
+	assert_eq!(monster.attack_learn(AttackType::Splash, None), false);
+	assert_eq!(monster.attack_learn(AttackType::VineWhip, Some(3)), false);
+	assert_eq!(monster.attack_learn(AttackType::VineWhip, Some(0)), true);
+	assert_eq!(monster.attacks()[0].attack_type(), AttackType::VineWhip);
+	assert_eq!(monster.attacks().len(), 3);
+
+	// Nothing new is learnable without gaining a level.
+	let level_up = monster.experience_add(1);
+	assert_eq!(level_up.levels(), 0);
+	assert!(level_up.attacks.is_empty());
+}
+
+// Validate that gaining a level in battle adds an effect with the newly learnable attacks.
+#[test]
+fn level_up_effect()
+{
+	let mut monster = Monster::new(SpeciesType::Bulbasaur, 6);
+	let growth = SpeciesType::Bulbasaur.species().growth;
+	let experience = growth.experience_with_level(7) - monster.experience_total() - 1;
+	monster.experience_add(experience);
+
+	let mut party_data0 = [monster];
+	let mut party_data1 = [monster_weak()];
+	let parties = vec![Party::new(&mut party_data0, 0, 1, true),
+		Party::new(&mut party_data1, 1, 1, false)];
+	let mut battle = Battle::with_seed(parties, 3);
+	assert_eq!(battle.command_add_attack(0, 0, 0, 1, 0), BattleError::None);
+	assert_eq!(battle.command_add_attack(1, 0, 0, 0, 0), BattleError::None);
+
+	let mut level_up = None;
+	for effect in finish_effects(&mut battle, BattleOutcome::Win(0))
+	{
+		if let Effect::LevelUp(effect) = effect
+		{
+			level_up = Some(effect);
+		}
+	}
+
+	let level_up = level_up.expect("Expected the member to level up.");
+	assert_eq!((level_up.party, level_up.member), (0, 0));
+	assert_eq!(level_up.level_up.level, 7);
+	assert_eq!(level_up.level_up.attacks, vec![(7, AttackType::LeechSeed)]);
+	assert_eq!(battle.state().parties()[0].member(0).level(), 7);
+}
+
+// Validate that attacks can be learned in between turns and are learned again during playback.
+#[test]
+fn level_up_learn_battle()
+{
+	let mut party_data0 = [Monster::new(SpeciesType::Bulbasaur, 6)];
+	let mut party_data1 = [monster_splash(SpeciesType::Mew, 10)];
+	let parties = vec![Party::new(&mut party_data0, 0, 1, false),
+		Party::new(&mut party_data1, 1, 1, false)];
+	let mut battle = Battle::with_seed(parties, 6);
+	assert_eq!(battle.member_attack_learn(0, 0, AttackType::Splash, None), BattleError::AttackLearn);
+	assert_eq!(battle.member_attack_learn(0, 0, AttackType::LeechSeed, None), BattleError::None);
+
+	// Attacks cannot be learned once commands were added.
+	assert_eq!(battle.command_add_attack(0, 0, 2, 1, 0), BattleError::None);
+	assert_eq!(battle.member_attack_learn(0, 0, AttackType::VineWhip, Some(0)),
+		BattleError::Rejected);
+	assert_eq!(battle.command_add_attack(1, 0, 0, 0, 0), BattleError::None);
+	turn_effects(&mut battle);
+	{
+		let seeded = battle.state().parties()[1].member(0);
+		assert!(seeded.health() < seeded.stat_health());
+	}
+
+	assert_eq!(battle.member_attack_learn(0, 0, AttackType::VineWhip, Some(0)), BattleError::None);
+	assert_eq!(battle.command_add_attack(0, 0, 0, 1, 0), BattleError::None);
+	assert_eq!(battle.command_add_attack(1, 0, 0, 0, 0), BattleError::None);
+	turn_effects(&mut battle);
+	assert_eq!(battle.state().parties()[0].member(0).attacks()[0].attack_type(),
+		AttackType::VineWhip);
+
+	let replay = battle.replay();
+	assert_eq!(replay.learn_count(), 2);
+	let mut text = Vec::new();
+	replay.save_toml(&mut text).unwrap();
+	let loaded = BattleReplay::load_toml(&mut &text[..]).unwrap();
+	assert_eq!(loaded.learn_count(), 2);
+	assert_eq!(*loaded.learn(1), *replay.learn(1));
+
+	let mut members = replay.party_members();
+	{
+		let mut player = BattleReplayPlayer::with_verify(replay, &mut members);
+		player.run_all();
+	}
+	assert_eq!(members[0][0].attacks()[0].attack_type(), AttackType::VineWhip);
+	assert_eq!(members[0][0].attacks()[2].attack_type(), AttackType::LeechSeed);
+}
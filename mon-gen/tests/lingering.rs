@@ -1,11 +1,14 @@
 extern crate mon_gen;
 
+mod common;
+
 use mon_gen::attack::AttackType;
 use mon_gen::battle::
 {
 	Battle,
 	BattleError,
 	BattleExecution,
+	BattleOutcome,
 	BattlePartyMember,
 	CommandAttack,
 	CommandType,
@@ -19,6 +22,8 @@ use mon_gen::battle::
 use mon_gen::monster::Monster;
 use mon_gen::species::SpeciesType;
 
+use common::execute;
+
 fn command_attack_none(battle: &mut Battle)
 {
 	assert_eq!(battle.execute(), BattleExecution::Command);
@@ -106,3 +111,48 @@ fn lingering_death_turns()
 		member: 0,
 	}));
 }
+
+// Uses Perish Song and waits for the battle to finish, returning how it ended.
+fn lingering_death_outcome(party_data0: &mut [Monster], party_data1: &mut [Monster])
+	-> BattleExecution
+{
+	let parties = vec![Party::new(party_data0, 0, 1, false), Party::new(party_data1, 1, 1, false)];
+	let mut battle = Battle::with_seed(parties, 5);
+	assert_eq!(battle.command_add_attack(0, 0, 1, 1, 0), BattleError::None);
+	for _ in 0..5
+	{
+		assert_eq!(battle.command_add_attack(1, 0, 0, 0, 0), BattleError::None);
+		let execution = execute(&mut battle).execution;
+		if execution != BattleExecution::Waiting
+		{
+			return execution;
+		}
+		assert_eq!(battle.command_add_attack(0, 0, 0, 1, 0), BattleError::None);
+	}
+	panic!("Expected the battle to finish.");
+}
+
+fn monster_perish() -> Monster
+{
+	let mut monster = Monster::new(SpeciesType::Mew, 100);
+	assert_eq!(monster.attack_add(AttackType::Splash), true);
+	assert_eq!(monster.attack_set(AttackType::Splash, 0), true);
+	assert_eq!(monster.attack_set(AttackType::PerishSong, 1), true);
+	monster
+}
+
+// Validate that the battle is a draw when every side faints at the end of the same turn, and that
+// a side with members left to switch in wins.
+#[test]
+fn lingering_death_draw()
+{
+	let mut party_data0 = [monster_perish()];
+	let mut party_data1 = [monster_perish()];
+	assert_eq!(lingering_death_outcome(&mut party_data0, &mut party_data1),
+		BattleExecution::Finished(BattleOutcome::Draw));
+
+	let mut party_data0 = [monster_perish()];
+	let mut party_data1 = [monster_perish(), monster_perish()];
+	assert_eq!(lingering_death_outcome(&mut party_data0, &mut party_data1),
+		BattleExecution::Finished(BattleOutcome::Win(1)));
+}
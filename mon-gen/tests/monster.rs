@@ -0,0 +1,129 @@
+extern crate mon_gen;
+extern crate rand;
+
+use std::ffi::CString;
+use std::io::ErrorKind;
+
+use rand::{SeedableRng, StdRng};
+
+use mon_gen::monster::{Monster, MonsterBuilder, Trainer};
+use mon_gen::serialize::{MONSTER_VERSION, SerializeError};
+use mon_gen::species::SpeciesType;
+
+// Validate that saved monsters load with every value intact.
+#[test]
+fn monster_save_load()
+{
+	let mut monsters = vec![Monster::new(SpeciesType::Mew, 42), Monster::new(SpeciesType::Bulbasaur, 7)];
+	monsters[0].nick_raw_set(CString::new("Mewtwo?").unwrap());
+	monsters[0].attacks_mut()[0].limit_left_take(3);
+	monsters[1].experience_add(10);
+	monsters[1].health_lose(5);
+
+	let mut text = Vec::new();
+	Monster::save_list_toml(&monsters, &mut text).unwrap();
+	let loaded = Monster::load_list_toml(&mut &text[..]).unwrap();
+	assert_eq!(format!("{:?}", loaded), format!("{:?}", monsters));
+	assert_eq!(loaded[0].nick(), "Mewtwo?");
+
+	let mut binary = Vec::new();
+	Monster::save_list_binary(&monsters, &mut binary).unwrap();
+	let loaded = Monster::load_list_binary(&mut &binary[..]).unwrap();
+	assert_eq!(format!("{:?}", loaded), format!("{:?}", monsters));
+}
+
+// Validate that names too long for the binary format fail to save instead of being cut off.
+#[test]
+fn monster_save_long_name()
+{
+	let mut monster = Monster::new(SpeciesType::Mew, 5);
+	monster.nick_raw_set(CString::new(vec![b'a'; 70000]).unwrap());
+	let mut binary = Vec::new();
+	let error = monster.write_binary(&mut binary).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::InvalidInput);
+
+	let trainer = Trainer::new(&"a".repeat(70000), 0);
+	let error = trainer.write_binary(&mut binary).unwrap_err();
+	assert_eq!(error.kind(), ErrorKind::InvalidInput);
+}
+
+// Validate that monster lists saved with another format version fail to load.
+#[test]
+fn monster_load_version()
+{
+	// The format has not been released yet, so it is still at its first version.
+	assert_eq!(MONSTER_VERSION, 1);
+
+	let mut text = Vec::new();
+	Monster::save_list_toml(&[Monster::new(SpeciesType::Mew, 5)], &mut text).unwrap();
+	let text = String::from_utf8(text).unwrap();
+	assert!(text.contains("version = 1"));
+	match Monster::load_list_toml(&mut text.replace("version = 1", "version = 2").as_bytes())
+	{
+		Err(SerializeError::VersionMismatch(2)) => {}
+		_ => panic!("Expected monster list with a different version to fail loading."),
+	}
+}
+
+// Validate that monsters referring to missing species fail to load.
+#[test]
+fn monster_load_stale()
+{
+	let mut text = Vec::new();
+	Monster::save_list_toml(&[Monster::new(SpeciesType::Mew, 5)], &mut text).unwrap();
+	let text = String::from_utf8(text).unwrap();
+	let species_line = format!("species = {}", SpeciesType::Mew as u16);
+	let text = text.replace(&species_line, "species = 9999");
+
+	match Monster::load_list_toml(&mut text.as_bytes())
+	{
+		Err(SerializeError::InvalidSpecies(9999)) => {}
+		_ => panic!("Expected monster with missing species to fail loading."),
+	}
+}
+
+// Validate that saved values a builder would reject fail to load.
+#[test]
+fn monster_load_invalid()
+{
+	let monster = MonsterBuilder::new(SpeciesType::Mew, 5)
+		.individuals([1, 2, 3, 4, 5, 6])
+		.build()
+		.unwrap();
+	let mut text = Vec::new();
+	Monster::save_list_toml(&[monster], &mut text).unwrap();
+	let text = String::from_utf8(text).unwrap();
+	assert!(Monster::load_list_toml(&mut text.as_bytes()).is_ok());
+
+	let changes = [("individual = [1, 2, 3, 4, 5, 6]", "individual = [200, 2, 3, 4, 5, 6]"),
+		("effort = [0, 0, 0, 0, 0, 0]", "effort = [252, 252, 100, 0, 0, 0]")];
+	for &(from, to) in &changes
+	{
+		assert!(text.contains(from));
+		match Monster::load_list_toml(&mut text.replace(from, to).as_bytes())
+		{
+			Err(SerializeError::InvalidData(_)) => {}
+			_ => panic!("Expected monster with `{}` to fail loading.", to),
+		}
+	}
+}
+
+// Validate that monsters created from generators in the same state are the same.
+#[test]
+fn monster_rng()
+{
+	let mut rng0 = StdRng::from_seed(&[3]);
+	let mut rng1 = StdRng::from_seed(&[3]);
+	for _ in 0..3
+	{
+		let monster0 = Monster::with_rng(SpeciesType::Pidgey, 12, &mut rng0);
+		let monster1 = Monster::with_rng(SpeciesType::Pidgey, 12, &mut rng1);
+		assert_eq!(format!("{:?}", monster0), format!("{:?}", monster1));
+	}
+
+	let builder = MonsterBuilder::new(SpeciesType::Bulbasaur, 30).personality(7);
+	let monster0 = builder.build_with_rng(&mut rng0).unwrap();
+	let monster1 = builder.build_with_rng(&mut rng1).unwrap();
+	assert_eq!(monster0.personality(), 7);
+	assert_eq!(format!("{:?}", monster0), format!("{:?}", monster1));
+}
@@ -1,9 +1,15 @@
 extern crate mon_gen;
 
-use mon_gen::monster::{Monster, StatType};
-use mon_gen::battle::Party;
+mod common;
+
+use std::thread;
+
+use mon_gen::monster::{Monster, RecruitMethod, StatType};
+use mon_gen::battle::{Battle, BattleError, BattleExecution, Party, PartyKind};
 use mon_gen::species::SpeciesType;
 
+use common::{experience_gains, monster_weak};
+
 // Make sure that active party members are chosen correctly.
 #[test]
 fn party_active_order()
@@ -70,3 +76,84 @@ fn party_active_order()
 		assert!(party.member_waiting_count() == 0);
 	}
 }
+
+// Make sure that owned parties can be sent across threads and hand back their members.
+#[test]
+fn party_owned()
+{
+	let members0 = vec![Monster::new(SpeciesType::Mew, 10), Monster::new(SpeciesType::Bulbasaur, 10)];
+	let members1 = vec![Monster::new(SpeciesType::Bulbasaur, 10)];
+	let health0 = members0[1].health();
+	let health1 = members1[0].health();
+
+	let parties = vec!
+	[
+		Party::with_owned(members0, 0, 1, false),
+		Party::with_owned(members1, 1, 1, false),
+	];
+	let mut battle = Battle::with_seed(parties, 3);
+
+	let members = thread::spawn(move ||
+	{
+		assert_eq!(battle.command_add_switch(0, 0, 1), BattleError::None);
+		assert_eq!(battle.command_add_attack(1, 0, 0, 0, 0), BattleError::None);
+		while battle.execute() != BattleExecution::Waiting {}
+		battle.into_members()
+	})
+	.join().unwrap();
+
+	// Members are returned in their original order with battle changes applied.
+	assert_eq!(members[0][0].species(), SpeciesType::Mew);
+	assert_eq!(members[0][1].species(), SpeciesType::Bulbasaur);
+	assert!(members[0][1].health() < health0);
+	assert_eq!(members[1][0].health(), health1);
+
+	let attack = &members[1][0].attacks()[0];
+	assert_eq!(attack.limit_left() + 1, attack.limit_max());
+}
+
+// Returns the experience gained by defeating a weak member of a party of the given kind.
+fn party_kind_experience(kind: PartyKind) -> Option<u32>
+{
+	let mut party_data0 = [Monster::new(SpeciesType::Bulbasaur, 10)];
+	let mut party_data1 = [monster_weak()];
+	let mut party1 = Party::new(&mut party_data1, 1, 1, false);
+	party1.kind_set(kind);
+	let parties = vec![Party::new(&mut party_data0, 0, 1, true), party1];
+	experience_gains(parties).get(&0).cloned()
+}
+
+// Validate that only members of wild parties can be recruited or escaped from.
+#[test]
+fn party_kind_rules()
+{
+	for &kind in &[PartyKind::Trainer, PartyKind::Link]
+	{
+		let mut party_data0 = [Monster::new(SpeciesType::Mew, 10)];
+		let mut party_data1 = [Monster::new(SpeciesType::Mew, 10)];
+		let mut party1 = Party::new(&mut party_data1, 1, 1, false);
+		party1.kind_set(kind);
+		assert_eq!(party1.kind(), kind);
+
+		let parties = vec![Party::new(&mut party_data0, 0, 1, false), party1];
+		let mut battle = Battle::with_seed(parties, 1);
+		assert_eq!(battle.command_add_recruit(0, 0, RecruitMethod::PokeBall, 1, 0),
+			BattleError::RecruitKind);
+		assert_eq!(battle.command_add_escape(0), BattleError::EscapeKind);
+
+		// The trainer can still escape from the wild party.
+		assert_eq!(battle.command_add_escape(1), BattleError::None);
+	}
+}
+
+// Validate that trainer parties give bonus experience and link parties give none.
+#[test]
+fn party_kind_bonus()
+{
+	let yield_base = SpeciesType::Pidgey.species().experience_yield as f32;
+	assert_eq!(party_kind_experience(PartyKind::Wild), Some((yield_base * 2f32 / 7f32).round()
+		as u32));
+	assert_eq!(party_kind_experience(PartyKind::Trainer), Some((yield_base * 2f32 * 1.5f32 /
+		7f32).round() as u32));
+	assert_eq!(party_kind_experience(PartyKind::Link), None);
+}
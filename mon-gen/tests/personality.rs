@@ -0,0 +1,50 @@
+extern crate mon_gen;
+
+use mon_gen::ability::AbilityType;
+use mon_gen::monster::{MonsterBuilder, ability_slot, shiny};
+use mon_gen::species::{Gender, GenderRatio, SpeciesType};
+
+// Validate that shininess depends on both the personality and the trainer.
+#[test]
+fn personality_shiny()
+{
+	assert!(shiny(0, 0));
+	assert!(shiny(0x12345678, 0x12345678));
+	assert!(shiny(0x00070000, 0));
+	assert!(!shiny(0x00080000, 0));
+	assert!(!shiny(0x12345678, 0));
+
+	let monster = MonsterBuilder::new(SpeciesType::Mew, 5).personality(0xABCD1234).build().unwrap();
+	assert!(monster.shiny(0x1234ABCD));
+	assert!(!monster.shiny(0));
+}
+
+// Validate that the gender and ability are derived from the personality unless chosen.
+#[test]
+fn personality_traits()
+{
+	assert_eq!(Gender::with_personality(0x00, GenderRatio::EqualMaleFemale), Gender::Male);
+	assert_eq!(Gender::with_personality(0x7F, GenderRatio::EqualMaleFemale), Gender::Male);
+	assert_eq!(Gender::with_personality(0x80, GenderRatio::EqualMaleFemale), Gender::Female);
+	assert_eq!(Gender::with_personality(0xDF, GenderRatio::SevenMaleToOne), Gender::Male);
+	assert_eq!(Gender::with_personality(0xE0, GenderRatio::SevenMaleToOne), Gender::Female);
+	assert_eq!(Gender::with_personality(0xFF, GenderRatio::Genderless), Gender::None);
+	assert_eq!(ability_slot(0x0100, 2), 1);
+	assert_eq!(ability_slot(0x0201, 2), 0);
+	assert_eq!(ability_slot(0xFFFF, 1), 0);
+
+	for personality in 0..512
+	{
+		let monster = MonsterBuilder::new(SpeciesType::Bulbasaur, 5).personality(personality)
+			.build().unwrap();
+		let species = SpeciesType::Bulbasaur.species();
+		assert_eq!(monster.gender(), Gender::with_personality(personality, species.gender));
+		assert_eq!(monster.ability(), species.abilities[ability_slot(personality,
+			species.abilities.len())]);
+	}
+
+	let monster = MonsterBuilder::new(SpeciesType::Bulbasaur, 5).personality(0)
+		.gender(Gender::Female).ability(AbilityType::Chlorophyll).build().unwrap();
+	assert_eq!(monster.gender(), Gender::Female);
+	assert_eq!(monster.ability(), AbilityType::Chlorophyll);
+}
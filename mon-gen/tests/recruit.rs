@@ -0,0 +1,93 @@
+extern crate mon_gen;
+
+mod common;
+
+use mon_gen::battle::{Battle, BattleError, BattleOutcome, BattleReplay, Effect, Party,
+	RecruitFailure, RecruitSuccess};
+use mon_gen::monster::{Location, Monster, RecruitMethod};
+use mon_gen::species::SpeciesType;
+
+use common::{finish_effects, monster_splash, turn_effects};
+
+// Validate that recruiting succeeds, ends the battle and records the method and location.
+#[test]
+fn recruit_success()
+{
+	let mut party_data0 = [monster_splash(SpeciesType::Mew, 50)];
+	let mut party_data1 = [monster_splash(SpeciesType::Mew, 10)];
+	{
+		let parties = vec![Party::new(&mut party_data0, 0, 1, false),
+			Party::new(&mut party_data1, 1, 1, false)];
+		let mut battle = Battle::with_seed(parties, 6);
+		battle.location_set(Location::Route1);
+		assert_eq!(battle.command_add_recruit(0, 0, RecruitMethod::MasterBall, 1, 0),
+			BattleError::None);
+		assert_eq!(battle.command_add_attack(1, 0, 0, 0, 0), BattleError::None);
+
+		let effects = finish_effects(&mut battle, BattleOutcome::Win(0));
+		assert!(effects.contains(&Effect::RecruitSuccess(RecruitSuccess
+		{
+			party: 0,
+			target_party: 1,
+			target_active: 0,
+			method: RecruitMethod::MasterBall,
+		})));
+
+		let mut text = Vec::new();
+		battle.replay().save_toml(&mut text).unwrap();
+		let replay = BattleReplay::load_toml(&mut &text[..]).unwrap();
+		assert_eq!(replay.location(), Some(Location::Route1));
+	}
+
+	let monster = &party_data1[0];
+	assert!(monster.recruited());
+	assert_eq!(monster.recruit_method(), RecruitMethod::MasterBall);
+	assert_eq!(monster.recruit_location(), Some(Location::Route1));
+
+	let mut data = Vec::new();
+	Monster::save_list_binary(&party_data1, &mut data).unwrap();
+	let loaded = Monster::load_list_binary(&mut &data[..]).unwrap();
+	assert_eq!(loaded[0].recruit_location(), Some(Location::Route1));
+}
+
+// Validate that failing to recruit a rare species with full health lets the battle go on.
+#[test]
+fn recruit_failure()
+{
+	let mut party_data0 = [monster_splash(SpeciesType::Mew, 50)];
+	let mut party_data1 = [Monster::new(SpeciesType::Deoxys, 10)];
+	{
+		let parties = vec![Party::new(&mut party_data0, 0, 1, false),
+			Party::new(&mut party_data1, 1, 1, false)];
+		let mut battle = Battle::with_seed(parties, 6);
+		assert_eq!(battle.command_add_recruit(0, 0, RecruitMethod::PokeBall, 1, 0),
+			BattleError::None);
+		assert_eq!(battle.command_add_attack(1, 0, 0, 0, 0), BattleError::None);
+
+		let effects = turn_effects(&mut battle);
+		assert!(effects.contains(&Effect::RecruitFailure(RecruitFailure
+		{
+			party: 1,
+			active: 0,
+		})));
+	}
+	assert!(!party_data1[0].recruited());
+}
+
+// Validate that members on the same side or that already belong to someone cannot be recruited.
+#[test]
+fn recruit_errors()
+{
+	let mut monster_owned = monster_splash(SpeciesType::Mew, 10);
+	monster_owned.recruit_set(RecruitMethod::PokeBall, None);
+
+	let mut party_data0 = [monster_splash(SpeciesType::Mew, 10)];
+	let mut party_data1 = [monster_owned];
+	let parties = vec![Party::new(&mut party_data0, 0, 1, false),
+		Party::new(&mut party_data1, 1, 1, false)];
+	let mut battle = Battle::with_seed(parties, 1);
+	assert_eq!(battle.command_add_recruit(0, 0, RecruitMethod::PokeBall, 0, 0),
+		BattleError::RecruitTarget);
+	assert_eq!(battle.command_add_recruit(0, 0, RecruitMethod::PokeBall, 1, 0),
+		BattleError::RecruitOwned);
+}
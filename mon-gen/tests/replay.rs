@@ -0,0 +1,185 @@
+extern crate mon_gen;
+
+mod common;
+
+use mon_gen::attack::AttackType;
+use mon_gen::battle::{Battle, BattleError, BattleExecution, BattleOutcome, BattleReplay,
+	BattleReplayPlayer, Party};
+use mon_gen::monster::{Monster, MonsterBuilder};
+use mon_gen::serialize::{REPLAY_VERSION, SerializeError};
+use mon_gen::species::SpeciesType;
+
+use common::monster_weak;
+
+// Plays back the given replay and returns the health of every party member.
+fn replay_health(replay: &BattleReplay) -> Vec<Vec<u16>>
+{
+	let mut members = replay.party_members();
+	let mut player = BattleReplayPlayer::new(replay, &mut members);
+	player.run_all();
+	player.runner().state().parties().iter().map(|party|
+	{
+		party.iter().map(|member| member.health()).collect()
+	})
+	.collect()
+}
+
+// Validate that playing back a replay regenerates the original battle.
+#[test]
+fn replay_playback()
+{
+	let mut party_data0 = [Monster::new(SpeciesType::Mew, 50), Monster::new(SpeciesType::Bulbasaur, 5)];
+	let mut party_data1 = [Monster::new(SpeciesType::Bulbasaur, 5)];
+
+	let mut party_health = Vec::new();
+	{
+		let parties = vec!
+		[
+			Party::new(&mut party_data0, 0, 1, true),
+			Party::new(&mut party_data1, 1, 1, true),
+		];
+
+		let mut battle = Battle::with_seed(parties, 7);
+		assert_eq!(battle.command_add_switch(0, 0, 1), BattleError::None);
+		assert_eq!(battle.command_add_attack(1, 0, 0, 0, 0), BattleError::None);
+		while battle.execute() != BattleExecution::Waiting {}
+
+		assert_eq!(battle.command_add_attack(0, 0, 0, 1, 0), BattleError::None);
+		assert_eq!(battle.command_add_attack(1, 0, 0, 0, 0), BattleError::None);
+		while battle.execute() != BattleExecution::Waiting {}
+
+		for party in battle.state().parties()
+		{
+			party_health.push(party.iter().map(|member| member.health()).collect::<Vec<_>>());
+		}
+
+		let replay = battle.replay();
+		assert!(replay.step_count() > 0);
+
+		let mut members = replay.party_members();
+		{
+			let mut player = BattleReplayPlayer::with_verify(replay, &mut members);
+			player.run_all();
+			assert_eq!(player.run(), None);
+			for (party, health) in player.runner().state().parties().iter().zip(&party_health)
+			{
+				assert_eq!(party.iter().map(|member| member.health()).collect::<Vec<_>>(), *health);
+			}
+		}
+	}
+}
+
+// Validate that saved replays load and play back the same battle.
+#[test]
+fn replay_save_load()
+{
+	let mut party_data0 = [Monster::new(SpeciesType::Mew, 10)];
+	let mut party_data1 = [Monster::new(SpeciesType::Bulbasaur, 10)];
+
+	let parties = vec!
+	[
+		Party::new(&mut party_data0, 0, 1, true),
+		Party::new(&mut party_data1, 1, 1, true),
+	];
+
+	let mut battle = Battle::with_seed(parties, 12);
+	assert_eq!(battle.command_add_attack(0, 0, 0, 1, 0), BattleError::None);
+	assert_eq!(battle.command_add_attack(1, 0, 0, 0, 0), BattleError::None);
+	while battle.execute() != BattleExecution::Waiting {}
+
+	let health = replay_health(battle.replay());
+
+	let mut text = Vec::new();
+	battle.replay().save_toml(&mut text).unwrap();
+	let replay = BattleReplay::load_toml(&mut &text[..]).unwrap();
+	assert_eq!(replay.seed(), 12);
+	assert_eq!(replay.command_count(), battle.replay().command_count());
+	assert_eq!(replay_health(&replay), health);
+
+	let mut binary = Vec::new();
+	battle.replay().save_binary(&mut binary).unwrap();
+	let replay = BattleReplay::load_binary(&mut &binary[..]).unwrap();
+	assert_eq!(replay_health(&replay), health);
+
+	// Replays from different tables must be rejected.
+	let text = String::from_utf8(text).unwrap();
+	let checksum_start = text.find("species_checksum = \"").unwrap() + 20;
+	let digit = if &text[checksum_start..checksum_start + 1] == "0" { "1" } else { "0" };
+	let text_changed = format!("{}{}{}", &text[..checksum_start], digit, &text[checksum_start + 1..]);
+	match BattleReplay::load_toml(&mut text_changed.as_bytes())
+	{
+		Err(SerializeError::TableMismatch) => {}
+		_ => panic!("Expected replay with different table to fail loading."),
+	}
+}
+
+// Validate that replays saved with another format version fail to load.
+#[test]
+fn replay_version()
+{
+	// The format has not been released yet, so it is still at its first version.
+	assert_eq!(REPLAY_VERSION, 1);
+
+	let mut party_data0 = [Monster::new(SpeciesType::Mew, 10)];
+	let mut party_data1 = [Monster::new(SpeciesType::Mew, 10)];
+	let parties = vec![Party::new(&mut party_data0, 0, 1, false),
+		Party::new(&mut party_data1, 1, 1, false)];
+	let battle = Battle::with_seed(parties, 1);
+
+	let mut text = Vec::new();
+	battle.replay().save_toml(&mut text).unwrap();
+	let text = String::from_utf8(text).unwrap();
+	assert!(text.contains("version = 1"));
+	match BattleReplay::load_toml(&mut text.replace("version = 1", "version = 2").as_bytes())
+	{
+		Err(SerializeError::VersionMismatch(2)) => {}
+		_ => panic!("Expected replay with a different version to fail loading."),
+	}
+}
+
+// Validate that the outcome of a finished battle is recorded and verified during playback.
+#[test]
+fn replay_finished()
+{
+	let mut party_data0 = [MonsterBuilder::new(SpeciesType::Mew, 50)
+		.attacks(&[AttackType::Pound])
+		.build()
+		.unwrap()];
+	let mut party_data1 = [monster_weak()];
+
+	let parties = vec!
+	[
+		Party::new(&mut party_data0, 0, 1, false),
+		Party::new(&mut party_data1, 1, 1, false),
+	];
+
+	let mut battle = Battle::with_seed(parties, 3);
+	assert_eq!(battle.command_add_attack(0, 0, 0, 1, 0), BattleError::None);
+	assert_eq!(battle.command_add_attack(1, 0, 0, 0, 0), BattleError::None);
+	let finished = BattleExecution::Finished(BattleOutcome::Win(0));
+	loop
+	{
+		let execution = battle.execute();
+		assert!(execution != BattleExecution::Waiting);
+		if execution == finished
+		{
+			break;
+		}
+	}
+	assert_eq!(battle.execute(), finished);
+
+	// The outcome is recorded once, no matter how often it is returned.
+	let replay = battle.replay();
+	let last = replay.step_count() - 1;
+	assert_eq!(replay.step(last).execution, finished);
+	assert!(replay.step(last - 1).execution != finished);
+
+	let mut members = replay.party_members();
+	let mut player = BattleReplayPlayer::with_verify(replay, &mut members);
+	let mut outcome = None;
+	while let Some(execution) = player.run()
+	{
+		outcome = Some(execution);
+	}
+	assert_eq!(outcome, Some(finished));
+}
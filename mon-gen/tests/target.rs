@@ -1,5 +1,12 @@
 extern crate mon_gen;
 
+mod common;
+
+use mon_gen::attack::AttackType;
+use mon_gen::battle::{Battle, BattleError, Effect, Party};
+
+use common::{monster_attacks, turn_effects};
+
 // Validate multi-target attacks hit targets they claim.
 #[test]
 fn battle_target()
@@ -13,3 +20,41 @@ fn battle_target()
 	// Make sure that with all of these, target cancels out when dead.
 	// But does not cancel out if target is multiple.
 }
+
+// Validate that parties on the same side are allies and that positions are shared by the side.
+#[test]
+fn target_sides()
+{
+	let mut party_data0 = [monster_attacks(&[AttackType::Pound, AttackType::Surf])];
+	let mut party_data1 = [monster_attacks(&[AttackType::Pound, AttackType::Splash])];
+	let mut party_data2 = [monster_attacks(&[AttackType::Splash]),
+		monster_attacks(&[AttackType::Splash])];
+	let parties = vec![Party::new(&mut party_data0, 0, 1, false),
+		Party::new(&mut party_data1, 0, 1, false), Party::new(&mut party_data2, 1, 2, false)];
+	let mut battle = Battle::with_seed(parties, 2);
+	assert_eq!(battle.state().position(0, 0), 0);
+	assert_eq!(battle.state().position(1, 0), 1);
+	assert_eq!(battle.state().position(2, 1), 1);
+
+	// Allies in another party cannot be attacked by attacks that only hit enemies.
+	assert_eq!(battle.command_add_attack(0, 0, 0, 1, 0), BattleError::AttackTarget);
+	assert_eq!(battle.command_add_attack(1, 0, 0, 0, 0), BattleError::AttackTarget);
+	assert_eq!(battle.command_add_attack(0, 0, 0, 2, 1), BattleError::None);
+
+	assert_eq!(battle.command_add_attack(0, 0, 1, 2, 0), BattleError::None);
+	assert_eq!(battle.command_add_attack(1, 0, 1, 2, 0), BattleError::None);
+	assert_eq!(battle.command_add_attack(2, 0, 0, 0, 0), BattleError::None);
+	assert_eq!(battle.command_add_attack(2, 1, 0, 1, 0), BattleError::None);
+
+	// Surf hits every adjacent member on both sides except the user.
+	let mut targets = Vec::new();
+	for effect in turn_effects(&mut battle)
+	{
+		if let Effect::Damage(ref damage) = effect
+		{
+			targets.push((damage.party, damage.active));
+		}
+	}
+	targets.sort();
+	assert_eq!(targets, vec![(1, 0), (2, 0), (2, 1)]);
+}
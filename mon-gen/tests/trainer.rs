@@ -0,0 +1,90 @@
+extern crate mon_gen;
+
+mod common;
+
+use mon_gen::battle::{Battle, BattleError, BattleOutcome, BattleReplay, Party};
+use mon_gen::monster::{Location, Monster, RecruitMethod, SubLocation, Trainer};
+use mon_gen::species::SpeciesType;
+
+use common::{experience_gains, finish_effects, monster_splash, monster_weak};
+
+// Defeats a weak member and returns the experience gained by the first member.
+fn experience_gain(monster: Monster, trainer: Option<Trainer>) -> u32
+{
+	let mut party_data0 = [monster];
+	let mut party_data1 = [monster_weak()];
+	let mut party0 = Party::new(&mut party_data0, 0, 1, true);
+	if let Some(trainer) = trainer
+	{
+		party0.trainer_set(trainer);
+	}
+	experience_gains(vec![party0, Party::new(&mut party_data1, 1, 1, false)])[&0]
+}
+
+// Validate that members recruited by a trainer remember the trainer and where and when it happened.
+#[test]
+fn trainer_recruit()
+{
+	let trainer = Trainer::new("Red", 0x12345678);
+
+	let mut party_data0 = [monster_splash(SpeciesType::Mew, 50)];
+	let mut party_data1 = [monster_splash(SpeciesType::Mew, 10)];
+	{
+		let mut party0 = Party::new(&mut party_data0, 0, 1, false);
+		party0.trainer_set(trainer.clone());
+		let parties = vec![party0, Party::new(&mut party_data1, 1, 1, false)];
+		let mut battle = Battle::with_seed(parties, 6);
+		battle.location_set(Location::Route1);
+		battle.sub_location_set(SubLocation::Grass);
+		battle.date_set(1000000);
+		assert_eq!(battle.command_add_recruit(0, 0, RecruitMethod::MasterBall, 1, 0),
+			BattleError::None);
+		assert_eq!(battle.command_add_attack(1, 0, 0, 0, 0), BattleError::None);
+		finish_effects(&mut battle, BattleOutcome::Win(0));
+
+		let mut text = Vec::new();
+		battle.replay().save_toml(&mut text).unwrap();
+		let replay = BattleReplay::load_toml(&mut &text[..]).unwrap();
+		assert_eq!(replay.sub_location(), Some(SubLocation::Grass));
+		assert_eq!(replay.date(), Some(1000000));
+		assert_eq!(replay.party(0).trainer(), Some(&trainer));
+	}
+
+	let check = |monster: &Monster|
+	{
+		assert_eq!(monster.trainer(), Some(&trainer));
+		assert_eq!(monster.recruit_location(), Some(Location::Route1));
+		assert_eq!(monster.recruit_sub_location(), Some(SubLocation::Grass));
+		assert_eq!(monster.recruit_level(), 10);
+		assert_eq!(monster.recruit_date(), Some(1000000));
+		assert!(!monster.traded(Some(&trainer)));
+		assert!(monster.traded(Some(&Trainer::new("Blue", 1))));
+		assert!(!monster.traded(None));
+	};
+	check(&party_data1[0]);
+
+	let mut text = Vec::new();
+	Monster::save_list_toml(&party_data1, &mut text).unwrap();
+	check(&Monster::load_list_toml(&mut &text[..]).unwrap()[0]);
+
+	let mut data = Vec::new();
+	Monster::save_list_binary(&party_data1, &mut data).unwrap();
+	check(&Monster::load_list_binary(&mut &data[..]).unwrap()[0]);
+}
+
+// Validate that traded members gain more experience than ones still with their original trainer.
+#[test]
+fn trainer_traded_experience()
+{
+	let mut monster = Monster::new(SpeciesType::Bulbasaur, 10);
+	monster.trainer_set(Trainer::new("Red", 1));
+
+	let amount = experience_gain(monster.clone(), Some(Trainer::new("Red", 1)));
+	let amount_wild = experience_gain(monster.clone(), None);
+	let amount_traded = experience_gain(monster, Some(Trainer::new("Blue", 2)));
+
+	let yield_base = SpeciesType::Pidgey.species().experience_yield as f32;
+	assert_eq!(amount, (yield_base * 2f32 / 7f32).round() as u32);
+	assert_eq!(amount_wild, amount);
+	assert_eq!(amount_traded, (yield_base * 2f32 * 1.5f32 / 7f32).round() as u32);
+}
@@ -0,0 +1,153 @@
+extern crate mon_gen;
+
+mod common;
+
+use mon_gen::attack::{Ailment, AttackType};
+use mon_gen::battle::{Battle, BattleError, BattleOutcome, Effect, NoneReason, Party, Volatile,
+	VolatileRemove, VolatileType};
+use mon_gen::monster::Monster;
+use mon_gen::species::SpeciesType;
+
+use common::{finish_effects, monster_splash, turn_effects};
+
+// Validate that flinching cancels an attack only for the current turn.
+#[test]
+fn volatile_flinch()
+{
+	let mut party_data0 = [monster_splash(SpeciesType::Mew, 10)];
+	let mut party_data1 = [monster_splash(SpeciesType::Mew, 10)];
+	let mut party0 = Party::new(&mut party_data0, 0, 1, false);
+	party0.active_member_volatile_add(0, Volatile::Flinch);
+	let parties = vec![party0, Party::new(&mut party_data1, 1, 1, false)];
+
+	let mut battle = Battle::with_seed(parties, 2);
+	let flinched = Effect::None(NoneReason::Flinch);
+
+	assert_eq!(battle.command_add_attack(0, 0, 0, 1, 0), BattleError::None);
+	assert_eq!(battle.command_add_attack(1, 0, 0, 0, 0), BattleError::None);
+	let effects = turn_effects(&mut battle);
+	assert!(effects.contains(&flinched));
+	assert!(effects.contains(&Effect::VolatileRemove(VolatileRemove
+	{
+		party: 0,
+		active: 0,
+		volatile: VolatileType::Flinch,
+	})));
+
+	assert_eq!(battle.command_add_attack(0, 0, 0, 1, 0), BattleError::None);
+	assert_eq!(battle.command_add_attack(1, 0, 0, 0, 0), BattleError::None);
+	assert!(!turn_effects(&mut battle).contains(&flinched));
+}
+
+// Validate that trapped members cannot switch and that switching clears conditions.
+#[test]
+fn volatile_trap_switch()
+{
+	let mut party_data = [Monster::new(SpeciesType::Mew, 10), Monster::new(SpeciesType::Mew, 10)];
+	let mut party_data_other = [Monster::new(SpeciesType::Mew, 10)];
+	{
+		let mut party = Party::new(&mut party_data, 0, 1, false);
+		party.active_member_volatile_add(0, Volatile::Trap(2, 1, 0));
+		party.active_member_volatile_add(0, Volatile::Trap(1, 1, 0));
+		assert_eq!(party.active_member(0).volatile(), &[Volatile::Trap(1, 1, 0)]);
+
+		let parties = vec![party, Party::new(&mut party_data_other, 1, 1, false)];
+		let mut battle = Battle::with_seed(parties, 4);
+		assert_eq!(battle.command_add_switch(0, 0, 1), BattleError::SwitchTrapped);
+	}
+
+	let mut party = Party::new(&mut party_data, 0, 1, false);
+	party.active_member_volatile_add(0, Volatile::Confusion(3));
+	party.switch_active(0, 1);
+	assert!(party.active_member(0).volatile().is_empty());
+}
+
+// Validate that trap and leech seed end once the member that caused them switches out.
+#[test]
+fn volatile_source_switch()
+{
+	let mut monster_hurt = monster_splash(SpeciesType::Mew, 10);
+	monster_hurt.health_lose(10);
+
+	let mut party_data0 = [monster_splash(SpeciesType::Mew, 10), monster_hurt];
+	let mut party_data1 = [monster_splash(SpeciesType::Mew, 10),
+		monster_splash(SpeciesType::Mew, 10)];
+	{
+		let mut party1 = Party::new(&mut party_data1, 1, 2, false);
+		party1.active_member_volatile_add(0, Volatile::Trap(3, 0, 0));
+		party1.active_member_volatile_add(1, Volatile::LeechSeed(0, 0));
+		let parties = vec![Party::new(&mut party_data0, 0, 1, false), party1];
+		let mut battle = Battle::with_seed(parties, 4);
+		assert_eq!(battle.command_add_switch(0, 0, 1), BattleError::None);
+		assert_eq!(battle.command_add_attack(1, 0, 0, 0, 0), BattleError::None);
+		assert_eq!(battle.command_add_attack(1, 1, 0, 0, 0), BattleError::None);
+		turn_effects(&mut battle);
+
+		let party1 = &battle.state().parties()[1];
+		assert!(party1.active_member(0).volatile().is_empty());
+		assert!(party1.active_member(1).volatile().is_empty());
+
+		// The member that switched in is not healed by the seed planted by the one before it.
+		let switched = battle.state().parties()[0].active_member(0).member;
+		assert_eq!(switched.health(), switched.stat_health() - 10);
+	}
+	assert_eq!(party_data1[1].health(), party_data1[1].stat_health());
+}
+
+// Validate that leech seed drains health at the end of the turn.
+#[test]
+fn volatile_leech_seed()
+{
+	let monster_seed = Monster::new(SpeciesType::Bulbasaur, 7);
+	assert_eq!(monster_seed.attacks()[2].attack_type(), AttackType::LeechSeed);
+
+	let mut party_data0 = [monster_seed];
+	let mut party_data1 = [monster_splash(SpeciesType::Mew, 10)];
+	{
+		let parties = vec!
+		[
+			Party::new(&mut party_data0, 0, 1, false),
+			Party::new(&mut party_data1, 1, 1, false),
+		];
+		let mut battle = Battle::with_seed(parties, 6);
+		assert_eq!(battle.command_add_attack(0, 0, 2, 1, 0), BattleError::None);
+		assert_eq!(battle.command_add_attack(1, 0, 0, 0, 0), BattleError::None);
+		turn_effects(&mut battle);
+	}
+
+	let monster = &party_data1[0];
+	assert_eq!(monster.health(), monster.stat_health() - monster.stat_health() / 8);
+	assert_eq!(party_data0[0].health(), party_data0[0].stat_health());
+}
+
+// Validate that a lone member fainting from burn is not hurt again by leech seed in the same turn.
+#[test]
+fn volatile_leech_seed_fainted()
+{
+	let mut monster_seeded = monster_splash(SpeciesType::Mew, 10);
+	monster_seeded.ailment_set(Ailment::Burn, 0);
+	let health = monster_seeded.health();
+	monster_seeded.health_lose(health - 1);
+
+	let mut party_data0 = [monster_seeded];
+	let mut party_data1 = [monster_splash(SpeciesType::Mew, 10)];
+	let mut party0 = Party::new(&mut party_data0, 0, 1, false);
+	party0.active_member_volatile_add(0, Volatile::LeechSeed(1, 0));
+	let parties = vec![party0, Party::new(&mut party_data1, 1, 1, true)];
+	let mut battle = Battle::with_seed(parties, 6);
+	assert_eq!(battle.command_add_attack(0, 0, 0, 1, 0), BattleError::None);
+	assert_eq!(battle.command_add_attack(1, 0, 0, 0, 0), BattleError::None);
+
+	// Experience is only gained once for the fainted member.
+	let effects = finish_effects(&mut battle, BattleOutcome::Win(1));
+	let gains = effects.iter().filter(|effect|
+	{
+		match **effect
+		{
+			Effect::ExperienceGain(_) => true,
+			_ => false,
+		}
+	}).count();
+	assert_eq!(gains, 1);
+	assert_eq!(battle.state().parties()[0].member(0).health(), 0);
+}
@@ -7,7 +7,7 @@ use mon_gen_c::base::monster::Monster;
 use mon_gen_c::monster;
 use mon_gen_c::species;
 
-use mon_gen::base::types::monster::LevelType;
+use mon_gen::base::types::monster::{LevelType, TrainerIdType};
 
 fn bool_cast_gm(b: bool) -> c_double
 {
@@ -141,6 +141,17 @@ pub extern fn mon_monster_get_personality_gm(monster: c_double) -> c_double
 	}
 }
 
+#[no_mangle]
+pub extern fn mon_monster_shiny_gm(monster: c_double, trainer: c_double) -> c_double
+{
+	let index = monster as usize;
+	unsafe
+	{
+		bool_cast_gm(monster::mon_monster_shiny(*(*monster_map).get(&index).unwrap(),
+			trainer as TrainerIdType))
+	}
+}
+
 #[no_mangle]
 pub extern fn mon_monster_get_gender_gm(monster: c_double) -> c_double
 {